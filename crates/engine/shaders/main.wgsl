@@ -13,14 +13,23 @@ struct VSOut {
 fn vs(
 	@builtin(vertex_index) index: u32,
 	@location(0) in_pos: vec4<f32>,
+	@location(1) in_color: vec4<f32>,
 ) -> VSOut {
     var vs_out: VSOut;
-    vs_out.pos = render.mvp * in_pos;
-    vs_out.color = vec4(1, 0, 0, 1);
+    //mvp is uploaded row-major, so wgsl sees its transpose
+    vs_out.pos = in_pos * render.mvp;
+    vs_out.color = in_color;
     return vs_out;
 }
 
+//colors arrive linear, but the canvas format does not encode to srgb
+fn encode_srgb(linear: vec3<f32>) -> vec3<f32> {
+    let low = linear * 12.92;
+    let high = 1.055 * pow(linear, vec3(1.0 / 2.4)) - 0.055;
+    return select(high, low, linear <= vec3(0.0031308));
+}
+
 @fragment
 fn fs(@location(0) inColor: vec4<f32>) -> @location(0) vec4<f32> {
-    return inColor;
+    return vec4(encode_srgb(inColor.rgb), inColor.a);
 }
@@ -43,7 +43,7 @@ impl Entities {
     }
 
     fn despawn(&mut self, entity: Entity) -> Option<EntityData> {
-        if entity > self.slots.len() {
+        if entity >= self.slots.len() {
             None?
         }
 
@@ -58,12 +58,12 @@ impl Entities {
         Some(data)
     }
 
-    fn get(&self, index: usize) -> &EntitySlot {
-        &self.slots[index]
+    fn get(&self, index: usize) -> Option<&EntityData> {
+        self.slots.get(index)?.as_ref()
     }
 
-    fn get_mut(&mut self, index: usize) -> &mut EntitySlot {
-        &mut self.slots[index]
+    fn get_mut(&mut self, index: usize) -> Option<&mut EntityData> {
+        self.slots.get_mut(index)?.as_mut()
     }
 }
 
@@ -91,6 +91,12 @@ pub trait Component: 'static {
     fn size() -> usize
     where
         Self: Sized;
+    fn align() -> usize
+    where
+        Self: Sized;
+    fn name() -> &'static str
+    where
+        Self: Sized;
 }
 
 impl<T: 'static> Component for T {
@@ -101,19 +107,49 @@ impl<T: 'static> Component for T {
     fn size() -> usize {
         mem::size_of::<T>()
     }
+
+    fn align() -> usize {
+        mem::align_of::<T>()
+    }
+
+    fn name() -> &'static str {
+        any::type_name::<T>()
+    }
 }
 
 #[derive(Default)]
 pub struct Components {
     storage: collections::HashMap<Archetype, Storage>,
+    //every archetype ever created, in creation order
+    archetypes: Vec<Archetype>,
+}
+
+impl Components {
+    fn storage_mut(&mut self, archetype: &Archetype) -> &mut Storage {
+        //storages freed by compaction keep their place in archetypes
+        if !self.storage.contains_key(archetype) && !self.archetypes.contains(archetype) {
+            self.archetypes.push(archetype.clone());
+        }
+
+        self.storage
+            .entry(archetype.clone())
+            .or_insert_with(|| Storage::new(archetype.clone()))
+    }
+
+    fn generation(&self) -> ArchetypeGeneration {
+        self.archetypes.len()
+    }
 }
 
 pub type ArchetypeIndex = usize;
+pub type ArchetypeGeneration = usize;
 
 #[derive(Clone, Default)]
 pub struct Archetype {
     ids: Vec<ComponentId>,
     size: Vec<usize>,
+    align: Vec<usize>,
+    names: Vec<&'static str>,
 }
 
 impl Archetype {
@@ -124,16 +160,24 @@ impl Archetype {
     fn add<T: Component>(&mut self) {
         self.ids.push(T::id());
         self.ids.sort();
-        self.size
-            .insert(self.index_of(&T::id()).unwrap(), T::size());
+        let index = self.index_of(&T::id()).unwrap();
+        self.size.insert(index, T::size());
+        self.align.insert(index, T::align());
+        self.names.insert(index, T::name());
     }
 
     fn remove_by_index(&mut self, index: usize) -> ComponentId {
         let id = self.ids.remove(index);
         self.size.remove(index);
+        self.align.remove(index);
+        self.names.remove(index);
         id
     }
 
+    pub fn names(&self) -> &[&'static str] {
+        &self.names
+    }
+
     fn get_by_index(&self, index: usize) -> ComponentId {
         *self.ids.get(index).unwrap()
     }
@@ -142,15 +186,53 @@ impl Archetype {
         self.ids.binary_search(id).ok()
     }
 
+    //components are laid out in id order, each padded to its alignment
     fn offset_of(&self, index: usize) -> Option<usize> {
         if index > self.size.len() {
             None?
         }
-        Some(self.size.iter().cloned().take(index).sum())
+
+        let mut offset: usize = 0;
+        for (size, align) in self.size.iter().zip(&self.align).take(index) {
+            offset = offset.next_multiple_of(*align) + size;
+        }
+
+        if let Some(align) = self.align.get(index) {
+            offset = offset.next_multiple_of(*align);
+        }
+
+        Some(offset)
     }
 
+    //rows are padded so every row in a storage stays aligned
     fn size(&self) -> usize {
-        self.size.iter().cloned().sum()
+        self.offset_of(self.len())
+            .unwrap()
+            .next_multiple_of(self.align())
+    }
+
+    fn align(&self) -> usize {
+        self.align.iter().cloned().max().unwrap_or(1)
+    }
+
+    //the bytes of every component in a row of this archetype
+    fn split<'a>(&self, row: &'a [u8]) -> Vec<&'a [u8]> {
+        (0..self.len())
+            .map(|index| {
+                let offset = self.offset_of(index).unwrap();
+                &row[offset..offset + self.size[index]]
+            })
+            .collect()
+    }
+
+    //lays components out as a row of this archetype, zeroing the padding
+    fn join<'a>(&self, components: impl IntoIterator<Item = &'a [u8]>) -> Vec<u8> {
+        let mut row = vec![0; self.size()];
+        for (index, bytes) in components.into_iter().enumerate() {
+            let offset = self.offset_of(index).unwrap();
+            row[offset..offset + bytes.len()].copy_from_slice(bytes);
+        }
+        row
     }
 
     fn len(&self) -> usize {
@@ -187,6 +269,9 @@ impl hash::Hash for Archetype {
 
 pub type StorageIndex = usize;
 
+//rows reserved by a new storage, after which it grows with its entity count
+const STORAGE_INITIAL_ROWS: usize = 8;
+
 #[derive(Default)]
 pub struct Insertion {
     archetype: Archetype,
@@ -204,18 +289,14 @@ impl Insertion {
         //SAFETY: bytes is used within the lifetime of component
         let bytes = unsafe { to_bytes(&component) };
 
+        let mut components = self.archetype.split(&self.data);
+
         self.archetype.add::<T>();
         let index = self.archetype.index_of(&T::id()).unwrap();
-        let offset = self.archetype.offset_of(index).unwrap();
+        components.insert(index, bytes);
 
-        self.data = self
-            .data
-            .iter()
-            .cloned()
-            .take(offset)
-            .chain(bytes.iter().cloned())
-            .chain(self.data.iter().cloned().skip(offset))
-            .collect::<Vec<_>>();
+        let data = self.archetype.join(components);
+        self.data = data;
 
         mem::forget(component);
     }
@@ -243,17 +324,11 @@ pub struct Removal {
 impl Removal {
     fn remove(&mut self, id: ComponentId) -> Vec<u8> {
         let index = self.archetype.index_of(&id).unwrap();
-        let offset = self.archetype.offset_of(index).unwrap();
-        let size = self.archetype.size[index];
-        let bytes = self.data[offset..offset + size].to_vec();
+        let mut components = self.archetype.split(&self.data);
+        let bytes = components.remove(index).to_vec();
         self.archetype.remove_by_index(index);
-        self.data = self
-            .data
-            .iter()
-            .cloned()
-            .take(offset)
-            .chain(self.data.iter().cloned().skip(offset + size))
-            .collect::<Vec<_>>();
+        let data = self.archetype.join(components);
+        self.data = data;
         bytes
     }
 }
@@ -261,16 +336,20 @@ impl Removal {
 #[derive(Default)]
 pub struct Storage {
     archetype: Archetype,
-    data: Vec<u8>,
+    //aligned to the archetype, so every component in it is aligned
+    data: AlignedBytes,
     entities: Vec<Entity>,
 }
 
 impl Storage {
     fn new(archetype: Archetype) -> Self {
         Self {
+            data: AlignedBytes::with_capacity(
+                archetype.align(),
+                archetype.size() * STORAGE_INITIAL_ROWS,
+            ),
+            entities: Vec::with_capacity(STORAGE_INITIAL_ROWS),
             archetype,
-            data: Vec::with_capacity(50000),
-            entities: vec![],
         }
     }
 
@@ -279,10 +358,10 @@ impl Storage {
             panic!("insertion archetype must match storage archetype");
         }
 
-        let component_index = self.data.len() / self.archetype.size();
+        let component_index = self.entities.len();
 
         self.entities.push(insertion.entity);
-        self.data.extend(insertion.into_data());
+        self.data.extend_from_slice(&insertion.into_data());
 
         component_index
     }
@@ -290,13 +369,13 @@ impl Storage {
     fn remove(&mut self, index: ComponentIndex) -> Option<Removal> {
         let size = self.archetype.size();
 
-        if index > self.data.len() / size {
+        if index >= self.entities.len() {
             None?
         }
 
         let data = self.data[index * size..index * size + size].to_vec();
 
-        let last_index = (self.data.len() / size) - 1;
+        let last_index = self.entities.len() - 1;
 
         let entity = self.entities[index];
 
@@ -337,12 +416,77 @@ impl Storage {
     fn location(&self) -> usize {
         self.data.as_ptr().cast::<u8>() as _
     }
+
+    fn compact(&mut self) {
+        self.data.shrink_to_fit();
+        self.entities.shrink_to_fit();
+    }
+
+    fn bytes_used(&self) -> usize {
+        self.data.len() + self.entities.len() * mem::size_of::<Entity>()
+    }
+
+    fn bytes_reserved(&self) -> usize {
+        self.data.capacity() + self.entities.capacity() * mem::size_of::<Entity>()
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct WorldStats {
+    pub archetypes: Vec<ArchetypeStats>,
+}
+
+impl WorldStats {
+    pub fn archetype_count(&self) -> usize {
+        self.archetypes.len()
+    }
+
+    pub fn entity_count(&self) -> usize {
+        self.archetypes.iter().map(|stats| stats.entities).sum()
+    }
+
+    pub fn bytes_used(&self) -> usize {
+        self.archetypes.iter().map(|stats| stats.bytes_used).sum()
+    }
+
+    pub fn bytes_reserved(&self) -> usize {
+        self.archetypes.iter().map(|stats| stats.bytes_reserved).sum()
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct ArchetypeStats {
+    pub archetype: ArchetypeIndex,
+    pub components: usize,
+    pub entities: usize,
+    pub bytes_used: usize,
+    pub bytes_reserved: usize,
 }
 
 #[derive(Default)]
 pub struct World {
     entities: Entities,
     components: Components,
+    cloners: collections::HashMap<ComponentId, Cloner>,
+}
+
+//clones a component out of its bytes into the bytes of the copy
+type Cloner = fn(&[u8]) -> Vec<u8>;
+
+fn clone_bytes<T: Component + Clone>(data: &[u8]) -> Vec<u8> {
+    if data.len() != T::size() {
+        panic!("byte slice must be same size as type");
+    }
+
+    //SAFETY: data holds a valid T taken from a storage row. the copy is
+    //never dropped, so the row stays the only owner of what T owns
+    let original = unsafe { data.as_ptr().cast::<T>().read_unaligned() };
+    let original = mem::ManuallyDrop::new(original);
+    let component = [T::clone(&original)];
+    //SAFETY: bytes is used within the lifetime of component
+    let bytes = unsafe { to_bytes(&component) }.to_vec();
+    mem::forget(component);
+    bytes
 }
 
 impl World {
@@ -351,7 +495,73 @@ impl World {
     }
 
     pub fn spawn(&mut self) -> Entity {
-        self.entities.spawn()
+        let entity = self.entities.spawn();
+
+        #[cfg(feature = "ecs-validate")]
+        self.validate();
+
+        entity
+    }
+
+    pub fn archetype_generation(&self) -> ArchetypeGeneration {
+        self.components.generation()
+    }
+
+    pub fn entities(&self) -> impl Iterator<Item = Entity> + '_ {
+        self.entities
+            .slots
+            .iter()
+            .enumerate()
+            .filter_map(|(entity, slot)| slot.as_ref().map(|_| entity))
+    }
+
+    pub fn archetype_of(&self, entity: Entity) -> Option<ArchetypeIndex> {
+        let archetype = self.entities.get(entity)?.archetype.as_ref()?;
+
+        self.components
+            .archetypes
+            .iter()
+            .position(|other| other == archetype)
+    }
+
+    pub fn archetype(&self, index: ArchetypeIndex) -> Option<&Archetype> {
+        self.components.archetypes.get(index)
+    }
+
+    pub fn stats(&self) -> WorldStats {
+        let archetypes = self
+            .components
+            .archetypes
+            .iter()
+            .enumerate()
+            .filter_map(|(index, archetype)| {
+                let storage = self.components.storage.get(archetype)?;
+
+                Some(ArchetypeStats {
+                    archetype: index,
+                    components: archetype.len(),
+                    entities: storage.entities.len(),
+                    bytes_used: storage.bytes_used(),
+                    bytes_reserved: storage.bytes_reserved(),
+                })
+            })
+            .collect();
+
+        WorldStats { archetypes }
+    }
+
+    //frees empty storages and shrinks the rest to fit their entities
+    pub fn compact(&mut self) {
+        self.components
+            .storage
+            .retain(|_, storage| !storage.entities.is_empty());
+
+        for storage in self.components.storage.values_mut() {
+            storage.compact();
+        }
+
+        #[cfg(feature = "ecs-validate")]
+        self.validate();
     }
 
     pub fn despawn(&mut self, entity: Entity) {
@@ -379,7 +589,14 @@ impl World {
             unsafe { ptr.drop_in_place() };
         }
 
-        let mut removal = storage.remove(component_index).unwrap();
+        let removal = storage.remove(component_index).unwrap();
+
+        if let Some(Swap { entity, to }) = removal.swap {
+            self.entities[entity].component_index = to;
+        }
+
+        #[cfg(feature = "ecs-validate")]
+        self.validate();
     }
 
     pub fn add<T: Component>(&mut self, entity: Entity, component: T) {
@@ -389,19 +606,22 @@ impl World {
 
         let Some(old_archetype) = &entity_data.archetype else {
             let mut insertion = Insertion::new();
+            insertion.entity = entity;
             insertion.add(component);
-            
-            let mut new_storage = self
-                .components
-                .storage
-                .entry(insertion.archetype().clone())
-                .or_insert(Storage::new(insertion.archetype().clone()));
-            
-            entity_data.component_index = new_storage.entities.len();
-            entity_data.archetype = Some(insertion.archetype().clone());
-
-            new_storage.insert(insertion);
-            
+
+            let new_archetype = insertion.archetype().clone();
+
+            let new_storage = self.components.storage_mut(&new_archetype);
+
+            entity_data.component_index = new_storage.insert(insertion);
+            entity_data.archetype = Some(new_archetype);
+            entity_data
+                .metadata
+                .insert(T::id(), ptr::metadata::<dyn Component>(ptr::null::<T>()));
+
+            #[cfg(feature = "ecs-validate")]
+            self.validate();
+
             return;
         };
 
@@ -411,9 +631,23 @@ impl World {
 
         let old_storage = self.components.storage.get_mut(&old_archetype).unwrap();
 
-        let old_storage_location = old_storage.location();
+        if old_archetype.contains(&T::id()) {
+            let size = old_archetype.size();
+            let index = old_archetype.index_of(&T::id()).unwrap();
+            let offset = old_archetype.offset_of(index).unwrap();
+            let data_location = old_storage.location() + component_index * size + offset;
+            let data_address = data_location as *mut T;
+            //SAFETY: the entity's row holds a valid, aligned T at data_address
+            let old_component = unsafe { data_address.replace(component) };
+            drop(old_component);
 
-        let mut removal = old_storage.remove(component_index).unwrap();
+            #[cfg(feature = "ecs-validate")]
+            self.validate();
+
+            return;
+        }
+
+        let removal = old_storage.remove(component_index).unwrap();
 
         let Removal {
             archetype,
@@ -436,45 +670,22 @@ impl World {
 
         let new_archetype = insertion.archetype().clone();
 
-        let new_storage = self
-            .components
-            .storage
-            .entry(new_archetype.clone())
-            .or_insert(Storage::new(insertion.archetype().clone()));
-
-        let old_component_index = entity_data.component_index;
-        
-        entity_data.component_index = new_storage.entities.len();
-        entity_data.archetype = Some(new_archetype.clone());
-
-        new_storage.insert(insertion);
-
-        let new_storage_location = new_storage.location();
+        let new_storage = self.components.storage_mut(&new_archetype);
 
-        let size = new_archetype.size();
-        let index = new_archetype.index_of(&T::id()).unwrap();
-        let offset = new_archetype.offset_of(index).unwrap();
-        let data_location = new_storage.location() + entity_data.component_index * size + offset;
-        let data_address = data_location as *const T;
-        let metadata = ptr::metadata::<dyn Component>(data_address);
-        entity_data.metadata.insert(T::id(), metadata);
-
-        drop(entity_data);
-        drop(new_storage);
-        let old_storage = self.components.storage.get(&old_archetype).unwrap();
-
-        let old_storage_location = old_storage.location();
+        entity_data.component_index = new_storage.insert(insertion);
+        entity_data.archetype = Some(new_archetype);
+        entity_data
+            .metadata
+            .insert(T::id(), ptr::metadata::<dyn Component>(ptr::null::<T>()));
 
+        //swap removal only moves the last row of the old storage,
+        //so no other entity needs its component index fixed up
         if let Some(Swap { entity, to }) = swap {
             self.entities[entity].component_index = to;
-            let old_archetype = self.entities[entity].archetype.as_ref().unwrap().clone();
         }
 
-        for &entity in &old_storage.entities {
-            if self.entities[entity].component_index > old_component_index {
-                self.entities[entity].component_index -= 1;
-            }
-        }
+        #[cfg(feature = "ecs-validate")]
+        self.validate();
     }
 
     pub fn remove<T: Component>(&mut self, entity: Entity) -> Option<T> {
@@ -512,51 +723,253 @@ impl World {
         if archetype.is_empty() {
             entity_data.component_index = 0;
             entity_data.archetype = None;
+        } else {
+            let insertion = Insertion {
+                archetype,
+                entity,
+                data,
+            };
+
+            let new_archetype = insertion.archetype().clone();
+
+            let new_storage = self.components.storage_mut(&new_archetype);
+
+            entity_data.component_index = new_storage.insert(insertion);
+            entity_data.archetype = Some(new_archetype);
         }
 
-        let mut insertion = Insertion {
+        if let Some(Swap { entity, to }) = swap {
+            self.entities[entity].component_index = to;
+        }
+
+        #[cfg(feature = "ecs-validate")]
+        self.validate();
+
+        //SAFETY: lifetime of bytes is extended to that of T
+        //since there is no more information where bytes came from (in storage),
+        //it is safe to cast it back to its original type
+        Some(unsafe { from_bytes(&bytes) })
+    }
+
+    //moves an entity and its components into other, returning its id there
+    pub fn extract(&mut self, entity: Entity, other: &mut World) -> Option<Entity> {
+        let entity_data = self.entities.despawn(entity)?;
+
+        let new_entity = other.entities.spawn();
+
+        let Some(archetype) = entity_data.archetype else {
+            #[cfg(feature = "ecs-validate")]
+            self.validate();
+
+            #[cfg(feature = "ecs-validate")]
+            other.validate();
+
+            return Some(new_entity);
+        };
+
+        let storage = self.components.storage.get_mut(&archetype).unwrap();
+
+        let Removal {
             archetype,
-            entity,
+            data,
+            swap,
+        } = storage.remove(entity_data.component_index).unwrap();
+
+        if let Some(Swap { entity, to }) = swap {
+            self.entities[entity].component_index = to;
+        }
+
+        let insertion = Insertion {
+            archetype,
+            entity: new_entity,
             data,
         };
 
         let new_archetype = insertion.archetype().clone();
 
-        let new_storage = self
-            .components
-            .storage
-            .entry(new_archetype.clone())
-            .or_insert(Storage::new(insertion.archetype().clone()));
+        let new_storage = other.components.storage_mut(&new_archetype);
 
-        let old_component_index = entity_data.component_index;
+        let new_entity_data = &mut other.entities[new_entity];
+        new_entity_data.component_index = new_storage.insert(insertion);
+        new_entity_data.archetype = Some(new_archetype);
+        new_entity_data.metadata = entity_data.metadata;
 
-        entity_data.component_index = new_storage.entities.len();
-        entity_data.archetype = Some(new_archetype.clone());
+        #[cfg(feature = "ecs-validate")]
+        self.validate();
 
-        new_storage.insert(insertion);
+        #[cfg(feature = "ecs-validate")]
+        other.validate();
 
-        let new_storage_location = new_storage.location();
+        Some(new_entity)
+    }
 
-        drop(entity_data);
-        drop(new_storage);
-        let old_storage = self.components.storage.get(&old_archetype).unwrap();
+    //moves every entity accepted by filter into other, returning (old, new) id pairs
+    pub fn move_entities(
+        &mut self,
+        mut filter: impl FnMut(&World, Entity) -> bool,
+        other: &mut World,
+    ) -> Vec<(Entity, Entity)> {
+        let entities = self
+            .entities()
+            .filter(|&entity| filter(self, entity))
+            .collect::<Vec<_>>();
 
-        let old_storage_location = old_storage.location();
+        entities
+            .into_iter()
+            .filter_map(|entity| Some((entity, self.extract(entity, other)?)))
+            .collect()
+    }
 
-        if let Some(Swap { entity, to }) = swap {
-            self.entities[entity].component_index = to;
+    pub fn register_clone<T: Component + Clone>(&mut self) {
+        self.cloners.insert(T::id(), clone_bytes::<T>);
+    }
+
+    //spawns a copy of entity, if every one of its components is registered as cloneable
+    pub fn clone_entity(&mut self, entity: Entity) -> Option<Entity> {
+        let entity_data = self.entities.get(entity)?;
+
+        let Some(archetype) = entity_data.archetype.clone() else {
+            return Some(self.spawn());
+        };
+
+        let cloners = archetype
+            .iter()
+            .map(|id| self.cloners.get(id).copied())
+            .collect::<Option<Vec<_>>>()?;
+
+        let metadata = entity_data.metadata.clone();
+
+        let storage = self.components.storage.get(&archetype).unwrap();
+
+        let size = archetype.size();
+        let row = &storage.data[entity_data.component_index * size..][..size];
+
+        let components = archetype
+            .split(row)
+            .into_iter()
+            .zip(cloners)
+            .map(|(bytes, cloner)| cloner(bytes))
+            .collect::<Vec<_>>();
+
+        let data = archetype.join(components.iter().map(Vec::as_slice));
+
+        let new_entity = self.entities.spawn();
+
+        let insertion = Insertion {
+            archetype,
+            entity: new_entity,
+            data,
+        };
+
+        let new_archetype = insertion.archetype().clone();
+
+        let storage = self.components.storage_mut(&new_archetype);
+
+        let new_entity_data = &mut self.entities[new_entity];
+        new_entity_data.component_index = storage.insert(insertion);
+        new_entity_data.archetype = Some(new_archetype);
+        new_entity_data.metadata = metadata;
+
+        #[cfg(feature = "ecs-validate")]
+        self.validate();
+
+        Some(new_entity)
+    }
+
+    pub fn get<T: Component>(&self, entity: Entity) -> Option<&T> {
+        let entity_data = self.entities.get(entity)?;
+
+        let archetype = entity_data.archetype.as_ref()?;
+
+        let index = archetype.index_of(&T::id())?;
+        let offset = archetype.offset_of(index).unwrap();
+
+        let storage = self.components.storage.get(archetype).unwrap();
+
+        let start = entity_data.component_index * archetype.size() + offset;
+
+        //SAFETY: the entity's row holds a valid T at start
+        Some(unsafe { ref_bytes(&storage.data[start..start + T::size()]) })
+    }
+
+    pub fn get_mut<T: Component>(&mut self, entity: Entity) -> Option<&mut T> {
+        let entity_data = self.entities.get(entity)?;
+
+        let archetype = entity_data.archetype.as_ref()?;
+
+        let index = archetype.index_of(&T::id())?;
+        let offset = archetype.offset_of(index).unwrap();
+
+        let start = entity_data.component_index * archetype.size() + offset;
+
+        let storage = self.components.storage.get_mut(archetype).unwrap();
+
+        //SAFETY: the entity's row holds a valid T at start
+        Some(unsafe { mut_bytes(&mut storage.data[start..start + T::size()]) })
+    }
+
+    //panics if any entity and the storage row it points at disagree
+    #[cfg(feature = "ecs-validate")]
+    pub fn validate(&self) {
+        for (entity, slot) in self.entities.slots.iter().enumerate() {
+            let Some(entity_data) = slot else {
+                assert!(
+                    self.entities.free.contains(&entity),
+                    "despawned entity {entity} is not free"
+                );
+                continue;
+            };
+
+            let Some(archetype) = &entity_data.archetype else {
+                assert!(
+                    entity_data.metadata.is_empty(),
+                    "entity {entity} has drop metadata but no archetype"
+                );
+                continue;
+            };
+
+            let storage = self
+                .components
+                .storage
+                .get(archetype)
+                .unwrap_or_else(|| panic!("entity {entity} has an archetype without storage"));
+
+            assert_eq!(
+                storage.entities.get(entity_data.component_index),
+                Some(&entity),
+                "entity {entity} points at row {} of a storage that does not hold it",
+                entity_data.component_index
+            );
+
+            assert!(
+                entity_data.metadata.len() == archetype.len()
+                    && archetype
+                        .iter()
+                        .all(|id| entity_data.metadata.contains_key(id)),
+                "entity {entity} has drop metadata that does not match its archetype"
+            );
         }
 
-        for &entity in &old_storage.entities {
-            if self.entities[entity].component_index > old_component_index {
-                self.entities[entity].component_index -= 1;
+        for storage in self.components.storage.values() {
+            assert_eq!(
+                storage.data.len(),
+                storage.entities.len() * storage.archetype.size(),
+                "storage data does not match its entity count"
+            );
+
+            for (row, &entity) in storage.entities.iter().enumerate() {
+                let entity_data = self
+                    .entities
+                    .get(entity)
+                    .unwrap_or_else(|| panic!("row {row} holds despawned entity {entity}"));
+
+                assert!(
+                    entity_data.archetype.as_ref() == Some(&storage.archetype)
+                        && entity_data.component_index == row,
+                    "row {row} holds entity {entity} which points elsewhere"
+                );
             }
         }
-
-        //SAFETY: lifetime of bytes is extended to that of T
-        //since there is no more information where bytes came from (in storage),
-        //it is safe to cast it back to its original type
-        Some(unsafe { from_bytes(&bytes) })
     }
 }
 
@@ -713,35 +1126,126 @@ pub struct Query<Q: QueryParameter> {
     marker: marker::PhantomData<Q>, 
 }
 
+pub struct QueryState<Q: QueryParameter> {
+    ids: Vec<ComponentId>,
+    generation: ArchetypeGeneration,
+    matches: Vec<QueryMatch>,
+    marker: marker::PhantomData<Q>,
+}
+
+pub struct QueryMatch {
+    archetype: ArchetypeIndex,
+    offsets: Vec<usize>,
+}
+
+impl QueryMatch {
+    pub fn archetype(&self) -> ArchetypeIndex {
+        self.archetype
+    }
+
+    pub fn offsets(&self) -> &[usize] {
+        &self.offsets
+    }
+}
+
+impl<Q: QueryParameter> QueryState<Q> {
+    pub fn new(world: &World) -> Self {
+        let mut ids = vec![];
+
+        Q::ids(&mut ids);
+
+        let mut state = Self {
+            ids,
+            generation: 0,
+            matches: vec![],
+            marker: marker::PhantomData,
+        };
+
+        state.update(world);
+
+        state
+    }
+
+    //only archetypes created since the last update need to be matched
+    pub fn update(&mut self, world: &World) {
+        let archetypes = &world.components.archetypes;
+
+        for (index, archetype) in archetypes.iter().enumerate().skip(self.generation) {
+            let Some(offsets) = self.offsets(archetype) else {
+                continue;
+            };
+
+            self.matches.push(QueryMatch {
+                archetype: index,
+                offsets,
+            });
+        }
+
+        self.generation = world.archetype_generation();
+    }
+
+    fn offsets(&self, archetype: &Archetype) -> Option<Vec<usize>> {
+        self.ids
+            .iter()
+            .map(|id| archetype.offset_of(archetype.index_of(id)?))
+            .collect()
+    }
+
+    pub fn matches(&self) -> &[QueryMatch] {
+        &self.matches
+    }
+
+    pub fn entities<'a>(&'a mut self, world: &'a World) -> impl Iterator<Item = Entity> + 'a {
+        self.update(world);
+
+        self.matches
+            .iter()
+            .filter_map(|query_match| {
+                let archetype = &world.components.archetypes[query_match.archetype];
+                world.components.storage.get(archetype)
+            })
+            .flat_map(|storage| storage.entities.iter().cloned())
+    }
+}
+
 pub trait QueryParameter: Send + Sync {
-    
+    fn ids(ids: &mut Vec<ComponentId>);
 }
 
 impl QueryParameter for Entity {
-
+    fn ids(_: &mut Vec<ComponentId>) {}
 }
 
 impl<'a, T> QueryParameter for &'a T 
     where T: 'static + Send + Sync
 {
-    
+    fn ids(ids: &mut Vec<ComponentId>) {
+        ids.push(T::id());
+    }
 }
 
 impl<'a, T> QueryParameter for &'a mut T
     where T: 'static + Send + Sync
 {
-
+    fn ids(ids: &mut Vec<ComponentId>) {
+        ids.push(T::id());
+    }
 }
 
 impl<A> QueryParameter for (A,) 
     where A: QueryParameter
 {
-
+    fn ids(ids: &mut Vec<ComponentId>) {
+        A::ids(ids);
+    }
 }
 
 impl<A, B> QueryParameter for (A, B) 
     where A: QueryParameter,
           B: QueryParameter,
 {
-
+    fn ids(ids: &mut Vec<ComponentId>) {
+        A::ids(ids);
+        B::ids(ids);
+    }
 }
@@ -3,7 +3,9 @@
 #![feature(box_syntax)]
 #![feature(ptr_metadata)]
 
-mod ecs;
+pub mod ecs;
+pub mod reflect;
+pub mod render;
 mod utils;
 
 //use crate::render::*;
@@ -16,6 +18,8 @@ pub struct TestDrop {
     payload: String,
 }
 
+reflect!(TestDrop { payload });
+
 impl Drop for TestDrop {
     fn drop(&mut self) {
         log("testing drop");
@@ -74,7 +78,10 @@ pub async fn start() -> Runtime {
         Vector::new([1.0, 1.0, 0.0, 1.0]),
     ]
     .into_iter()
-    .map(|position| render::Vertex { position })
+    .map(|position| render::Vertex {
+        position,
+        color: LinearRgba::RED,
+    })
     .collect::<Vec<_>>();
 
     let indices = [0, 1, 2];
@@ -86,7 +93,11 @@ pub async fn start() -> Runtime {
         .indices(&indices);
 
     Runtime { renderer }*/
-    Runtime {}
+    let mut registry = reflect::Registry::new();
+
+    registry.register::<usize>().register::<TestDrop>();
+
+    Runtime { world, registry }
 }
 
 pub fn dilog<T: std::fmt::Display + ?Sized>(data: &T) {
@@ -100,6 +111,8 @@ pub fn delog<T: std::fmt::Debug + ?Sized>(data: &T) {
 #[wasm_bindgen]
 pub struct Runtime {
     //renderer: &'static dyn Renderer,
+    world: ecs::World,
+    registry: reflect::Registry,
 }
 
 #[wasm_bindgen]
@@ -112,6 +125,57 @@ impl Runtime {
         self.renderer.render(render, "primary");
         */
     }
+
+    //json array of every entity with its archetype and component names
+    pub fn entities(&self) -> String {
+        self.registry.entities(&self.world).to_string()
+    }
+
+    //json object of an entity's component values, if the entity exists
+    pub fn inspect(&self, entity: usize) -> Option<String> {
+        self.registry
+            .inspect(&self.world, entity)
+            .map(|value| value.to_string())
+    }
+
+    pub fn set_field(&mut self, entity: usize, component: &str, field: &str, value: JsValue) -> bool {
+        let Some(value) = value_from_js(&value) else {
+            return false;
+        };
+
+        self.registry
+            .set_field(&mut self.world, entity, component, field, &value)
+    }
+}
+
+fn value_from_js(value: &JsValue) -> Option<reflect::Value> {
+    use reflect::Value;
+
+    if value.is_null() || value.is_undefined() {
+        return Some(Value::Null);
+    }
+
+    if let Some(value) = value.as_bool() {
+        return Some(Value::Bool(value));
+    }
+
+    if let Some(value) = value.as_f64() {
+        return Some(Value::Number(value));
+    }
+
+    if let Some(value) = value.as_string() {
+        return Some(Value::String(value));
+    }
+
+    if js_sys::Array::is_array(value) {
+        return js_sys::Array::from(value)
+            .iter()
+            .map(|value| value_from_js(&value))
+            .collect::<Option<Vec<_>>>()
+            .map(Value::Array);
+    }
+
+    None
 }
 
 #[wasm_bindgen]
@@ -0,0 +1,434 @@
+use crate::ecs::*;
+use math::prelude::*;
+use std::collections;
+use std::fmt;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<Value>),
+    Object(Vec<(String, Value)>),
+}
+
+impl fmt::Display for Value {
+    //formats the value as json
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fn escape(f: &mut fmt::Formatter<'_>, string: &str) -> fmt::Result {
+            write!(f, "\"")?;
+            for c in string.chars() {
+                match c {
+                    '"' => write!(f, "\\\"")?,
+                    '\\' => write!(f, "\\\\")?,
+                    '\n' => write!(f, "\\n")?,
+                    '\r' => write!(f, "\\r")?,
+                    '\t' => write!(f, "\\t")?,
+                    c if c.is_control() => write!(f, "\\u{:04x}", c as u32)?,
+                    c => write!(f, "{c}")?,
+                }
+            }
+            write!(f, "\"")
+        }
+
+        match self {
+            Value::Null => write!(f, "null"),
+            Value::Bool(value) => write!(f, "{value}"),
+            Value::Number(value) if value.is_finite() => write!(f, "{value}"),
+            Value::Number(_) => write!(f, "null"),
+            Value::String(value) => escape(f, value),
+            Value::Array(values) => {
+                write!(f, "[")?;
+                for (i, value) in values.iter().enumerate() {
+                    if i != 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{value}")?;
+                }
+                write!(f, "]")
+            }
+            Value::Object(fields) => {
+                write!(f, "{{")?;
+                for (i, (name, value)) in fields.iter().enumerate() {
+                    if i != 0 {
+                        write!(f, ",")?;
+                    }
+                    escape(f, name)?;
+                    write!(f, ":{value}")?;
+                }
+                write!(f, "}}")
+            }
+        }
+    }
+}
+
+pub trait ReflectValue: Sized {
+    fn to_value(&self) -> Value;
+    fn from_value(value: &Value) -> Option<Self>;
+}
+
+macro_rules! reflect_number {
+    ($($type:ty),*) => {
+        $(
+            impl ReflectValue for $type {
+                fn to_value(&self) -> Value {
+                    Value::Number(*self as f64)
+                }
+
+                //rejects numbers that would be truncated or saturated
+                fn from_value(value: &Value) -> Option<Self> {
+                    let Value::Number(number) = value else {
+                        None?
+                    };
+
+                    //MAX + 1 is a power of two, so exact as a float
+                    let in_range = *number >= <$type>::MIN as f64
+                        && *number < <$type>::MAX as f64 + 1.0;
+
+                    if !number.is_finite() || number.fract() != 0.0 || !in_range {
+                        None?
+                    }
+
+                    Some(*number as $type)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! reflect_float {
+    ($($type:ty),*) => {
+        $(
+            impl ReflectValue for $type {
+                fn to_value(&self) -> Value {
+                    Value::Number(*self as f64)
+                }
+
+                fn from_value(value: &Value) -> Option<Self> {
+                    let Value::Number(number) = value else {
+                        None?
+                    };
+
+                    let converted = *number as $type;
+
+                    //out of range numbers become infinite
+                    if !converted.is_finite() {
+                        None?
+                    }
+
+                    Some(converted)
+                }
+            }
+        )*
+    };
+}
+
+reflect_number!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+reflect_float!(f32, f64);
+
+impl ReflectValue for bool {
+    fn to_value(&self) -> Value {
+        Value::Bool(*self)
+    }
+
+    fn from_value(value: &Value) -> Option<Self> {
+        let Value::Bool(value) = value else { None? };
+        Some(*value)
+    }
+}
+
+impl ReflectValue for String {
+    fn to_value(&self) -> Value {
+        Value::String(self.clone())
+    }
+
+    fn from_value(value: &Value) -> Option<Self> {
+        let Value::String(value) = value else { None? };
+        Some(value.clone())
+    }
+}
+
+impl<T, const N: usize> ReflectValue for Vector<T, N>
+where
+    T: math::Numeric + ReflectValue,
+{
+    fn to_value(&self) -> Value {
+        Value::Array(self.iter().map(ReflectValue::to_value).collect())
+    }
+
+    fn from_value(value: &Value) -> Option<Self> {
+        let Value::Array(values) = value else { None? };
+
+        if values.len() != N {
+            None?
+        }
+
+        let mut vector = Vector::<T, N>::default();
+        for (component, value) in vector.iter_mut().zip(values) {
+            *component = T::from_value(value)?;
+        }
+        Some(vector)
+    }
+}
+
+impl<T> ReflectValue for Quaternion<T>
+where
+    T: math::Numeric + ReflectValue,
+{
+    fn to_value(&self) -> Value {
+        Value::Array(self.iter().map(ReflectValue::to_value).collect())
+    }
+
+    fn from_value(value: &Value) -> Option<Self> {
+        let Value::Array(values) = value else {
+            None?
+        };
+
+        if values.len() != 4 {
+            None?
+        }
+
+        let mut data = [T::default(); 4];
+        for (component, value) in data.iter_mut().zip(values) {
+            *component = T::from_value(value)?;
+        }
+        Some(Quaternion::new(data))
+    }
+}
+
+//an array of rows, like the matrix's serde format
+impl<T, const N: usize, const M: usize> ReflectValue for Matrix<T, N, M>
+where
+    T: math::Numeric + ReflectValue,
+{
+    fn to_value(&self) -> Value {
+        Value::Array(self.iter().map(ReflectValue::to_value).collect())
+    }
+
+    fn from_value(value: &Value) -> Option<Self> {
+        let Value::Array(values) = value else { None? };
+
+        if values.len() != M {
+            None?
+        }
+
+        let mut data = [Vector::<T, N>::default(); M];
+        for (row, value) in data.iter_mut().zip(values) {
+            *row = Vector::from_value(value)?;
+        }
+        Some(Matrix::new(data))
+    }
+}
+
+impl ReflectValue for LinearRgba {
+    fn to_value(&self) -> Value {
+        Value::Array(self.to_array().iter().map(ReflectValue::to_value).collect())
+    }
+
+    fn from_value(value: &Value) -> Option<Self> {
+        let [red, green, blue, alpha] = *Vector::<f32, 4>::from_value(value)?;
+        Some(LinearRgba::new(red, green, blue, alpha))
+    }
+}
+
+//components that expose their fields to the inspector
+pub trait Reflect: Component {
+    fn fields(&self) -> Vec<(&'static str, Value)>;
+    fn set_field(&mut self, field: &str, value: &Value) -> bool;
+}
+
+//implements Reflect for a struct from a list of its fields
+#[macro_export]
+macro_rules! reflect {
+    ($type:ty { $($field:ident),* $(,)? }) => {
+        impl $crate::reflect::Reflect for $type {
+            fn fields(&self) -> Vec<(&'static str, $crate::reflect::Value)> {
+                vec![$((
+                    stringify!($field),
+                    $crate::reflect::ReflectValue::to_value(&self.$field),
+                )),*]
+            }
+
+            fn set_field(&mut self, field: &str, value: &$crate::reflect::Value) -> bool {
+                match field {
+                    $(stringify!($field) => {
+                        let Some(value) = $crate::reflect::ReflectValue::from_value(value) else {
+                            return false;
+                        };
+                        self.$field = value;
+                        true
+                    })*
+                    _ => false,
+                }
+            }
+        }
+    };
+}
+
+//plain values are reflected as a single field called value
+macro_rules! reflect_plain {
+    ($($type:ty),*) => {
+        $(
+            impl Reflect for $type {
+                fn fields(&self) -> Vec<(&'static str, Value)> {
+                    vec![("value", self.to_value())]
+                }
+
+                fn set_field(&mut self, field: &str, value: &Value) -> bool {
+                    if field != "value" {
+                        return false;
+                    }
+                    let Some(value) = <$type>::from_value(value) else {
+                        return false;
+                    };
+                    *self = value;
+                    true
+                }
+            }
+        )*
+    };
+}
+
+reflect_plain!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, f32, f64, bool, String);
+
+reflect!(Transform<f32> {
+    translation,
+    rotation,
+    scale
+});
+
+struct Reflection {
+    name: &'static str,
+    fields: fn(&World, Entity) -> Option<Value>,
+    set_field: fn(&mut World, Entity, &str, &Value) -> bool,
+}
+
+fn fields<T: Reflect>(world: &World, entity: Entity) -> Option<Value> {
+    let component = world.get::<T>(entity)?;
+
+    let fields = component
+        .fields()
+        .into_iter()
+        .map(|(name, value)| (name.to_owned(), value))
+        .collect();
+
+    Some(Value::Object(fields))
+}
+
+fn set_field<T: Reflect>(world: &mut World, entity: Entity, field: &str, value: &Value) -> bool {
+    let Some(component) = world.get_mut::<T>(entity) else {
+        return false;
+    };
+
+    component.set_field(field, value)
+}
+
+#[derive(Default)]
+pub struct Registry {
+    reflections: collections::HashMap<ComponentId, Reflection>,
+}
+
+impl Registry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn register<T: Reflect>(&mut self) -> &mut Self {
+        self.reflections.insert(
+            T::id(),
+            Reflection {
+                name: T::name(),
+                fields: fields::<T>,
+                set_field: set_field::<T>,
+            },
+        );
+
+        self
+    }
+
+    //lists every entity with its archetype and component names
+    pub fn entities(&self, world: &World) -> Value {
+        let entities = world
+            .entities()
+            .map(|entity| {
+                let archetype = world.archetype_of(entity);
+
+                let names = archetype
+                    .and_then(|index| world.archetype(index))
+                    .map(|archetype| archetype.names())
+                    .unwrap_or_default()
+                    .iter()
+                    .map(|name| Value::String((*name).to_owned()))
+                    .collect();
+
+                Value::Object(vec![
+                    ("entity".to_owned(), Value::Number(entity as f64)),
+                    (
+                        "archetype".to_owned(),
+                        archetype.map_or(Value::Null, |index| Value::Number(index as f64)),
+                    ),
+                    ("components".to_owned(), Value::Array(names)),
+                ])
+            })
+            .collect();
+
+        Value::Array(entities)
+    }
+
+    //the values of an entity's components, null for unregistered components
+    pub fn inspect(&self, world: &World, entity: Entity) -> Option<Value> {
+        if !world.entities().any(|other| other == entity) {
+            None?
+        }
+
+        let archetype = world.archetype_of(entity);
+
+        let components = archetype
+            .and_then(|index| world.archetype(index))
+            .map(|archetype| {
+                archetype
+                    .iter()
+                    .zip(archetype.names())
+                    .map(|(id, name)| {
+                        let value = self
+                            .reflections
+                            .get(id)
+                            .and_then(|reflection| (reflection.fields)(world, entity))
+                            .unwrap_or(Value::Null);
+
+                        ((*name).to_owned(), value)
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Some(Value::Object(vec![
+            ("entity".to_owned(), Value::Number(entity as f64)),
+            (
+                "archetype".to_owned(),
+                archetype.map_or(Value::Null, |index| Value::Number(index as f64)),
+            ),
+            ("components".to_owned(), Value::Object(components)),
+        ]))
+    }
+
+    pub fn set_field(
+        &self,
+        world: &mut World,
+        entity: Entity,
+        component: &str,
+        field: &str,
+        value: &Value,
+    ) -> bool {
+        let Some(reflection) = self
+            .reflections
+            .values()
+            .find(|reflection| reflection.name == component)
+        else {
+            return false;
+        };
+
+        (reflection.set_field)(world, entity, field, value)
+    }
+}
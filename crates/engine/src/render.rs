@@ -1,17 +1,52 @@
+use math::pod::as_bytes;
 use math::prelude::*;
 use std::mem;
 use std::slice;
 
-pub async fn compatible() -> &'static mut dyn Renderer {
-    Box::leak(box webgpu::WebGpu::new().await.unwrap())
+pub mod recording;
+pub mod software;
+
+//the webgpu backend needs a browser and web_sys's unstable bindings, so native
+//builds only have the software and recording backends
+#[cfg(all(target_arch = "wasm32", web_sys_unstable_apis))]
+pub async fn compatible() -> &'static mut dyn Renderer<Batch = webgpu::Batch> {
+    Box::leak(Box::new(webgpu::WebGpu::new().await.unwrap()))
 }
 
 pub trait Renderer {
+    //the uploaded vertices and indices, in whatever form the backend draws from
+    type Batch: Mesh;
+
     async fn new() -> Result<Self, ()> where Self: Sized;
-    fn render(&mut self, render: Render, batches: &[&Batch]);
-    fn batch(&self, vertices: &[Vertex], indices: &[Index]) -> Batch;
+    //draws every batch in order, transformed by render.mvp * its model
+    fn render(&mut self, render: Render, batches: &[&Self::Batch]);
+    //vertices are packed as the layout describes. without indices, every
+    //three vertices in order make a triangle
+    fn batch_bytes(
+        &self,
+        layout: VertexBufferLayout,
+        vertices: &[u8],
+        indices: Option<&[Index]>,
+    ) -> Self::Batch;
+    fn batch<V: VertexLayout>(&self, vertices: &[V], indices: Option<&[Index]>) -> Self::Batch
+    where
+        Self: Sized,
+    {
+        self.batch_bytes(V::layout(), as_bytes(vertices), indices)
+    }
+    fn set_clear_color(&mut self, color: LinearRgba);
+}
+
+//what every backend's batch knows about itself
+pub trait Mesh {
+    fn vertex_count(&self) -> usize;
+    //none for batches drawn without indices
+    fn index_count(&self) -> Option<usize>;
+    fn model(&self) -> Matrix<f32, 4, 4>;
+    fn set_model(&mut self, model: Matrix<f32, 4, 4>);
 }
 
+#[cfg(all(target_arch = "wasm32", web_sys_unstable_apis))]
 fn get_play_canvas() -> Result<web_sys::HtmlCanvasElement, ()> {
     let window = web_sys::window().ok_or(())?;
 
@@ -30,25 +65,191 @@ fn get_play_canvas() -> Result<web_sys::HtmlCanvasElement, ()> {
 
 pub type Index = u16;
 
-#[derive(Clone, Copy)]
-pub struct Vertex {
-    pub position: Vector<f32, 4>,
+//the formats a vertex attribute can have, named like GpuVertexFormat
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum VertexFormat {
+    Float32,
+    Float32x2,
+    Float32x3,
+    Float32x4,
+    Uint32,
+    Uint32x2,
+    Uint32x3,
+    Uint32x4,
+    Sint32,
+    Sint32x2,
+    Sint32x3,
+    Sint32x4,
 }
 
-#[derive(Clone, Copy)]
-pub struct Render {
-    pub mvp: Matrix<f32, 4, 4>,
+impl VertexFormat {
+    pub const fn components(self) -> usize {
+        use VertexFormat::*;
+
+        match self {
+            Float32 | Uint32 | Sint32 => 1,
+            Float32x2 | Uint32x2 | Sint32x2 => 2,
+            Float32x3 | Uint32x3 | Sint32x3 => 3,
+            Float32x4 | Uint32x4 | Sint32x4 => 4,
+        }
+    }
+
+    //every component is 4 bytes
+    pub const fn size(self) -> usize {
+        self.components() * 4
+    }
 }
 
+//types a vertex field can have
+pub trait Attribute: Pod {
+    const FORMAT: VertexFormat;
+}
 
+macro_rules! attribute {
+    ($($type:ty => $format:ident),* $(,)?) => {
+        $(
+            impl Attribute for $type {
+                const FORMAT: VertexFormat = VertexFormat::$format;
+            }
+        )*
+    };
+}
+
+attribute!(
+    f32 => Float32,
+    [f32; 2] => Float32x2,
+    [f32; 3] => Float32x3,
+    [f32; 4] => Float32x4,
+    Vector<f32, 2> => Float32x2,
+    Vector<f32, 3> => Float32x3,
+    Vector<f32, 4> => Float32x4,
+    LinearRgba => Float32x4,
+    u32 => Uint32,
+    [u32; 2] => Uint32x2,
+    [u32; 3] => Uint32x3,
+    [u32; 4] => Uint32x4,
+    Vector<u32, 2> => Uint32x2,
+    Vector<u32, 3> => Uint32x3,
+    Vector<u32, 4> => Uint32x4,
+    i32 => Sint32,
+    [i32; 2] => Sint32x2,
+    [i32; 3] => Sint32x3,
+    [i32; 4] => Sint32x4,
+    Vector<i32, 2> => Sint32x2,
+    Vector<i32, 3> => Sint32x3,
+    Vector<i32, 4> => Sint32x4,
+);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct VertexAttribute {
+    pub name: &'static str,
+    pub format: VertexFormat,
+    //in bytes from the start of the vertex
+    pub offset: usize,
+}
+
+//the attributes main.wgsl reads, by name, and the shader location of each
+pub const SHADER_LOCATIONS: [(&str, u32); 2] = [("position", 0), ("color", 1)];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct VertexBufferLayout {
+    //in bytes from one vertex to the next
+    pub stride: usize,
+    pub attributes: &'static [VertexAttribute],
+}
+
+impl VertexBufferLayout {
+    pub fn attribute(&self, name: &str) -> Option<VertexAttribute> {
+        self.attributes
+            .iter()
+            .find(|attribute| attribute.name == name)
+            .copied()
+    }
+
+    //the attributes main.wgsl reads, each with its shader location, or none if
+    //the layout lacks one of them. attributes the shader doesn't read are skipped
+    pub fn shader_locations(&self) -> Option<Vec<(VertexAttribute, u32)>> {
+        SHADER_LOCATIONS
+            .iter()
+            .map(|(name, location)| Some((self.attribute(name)?, *location)))
+            .collect()
+    }
+}
+
+//vertex types a batch can be made of, usually declared with vertex!
+pub trait VertexLayout: Pod {
+    const ATTRIBUTES: &'static [VertexAttribute];
+
+    fn layout() -> VertexBufferLayout {
+        VertexBufferLayout {
+            stride: mem::size_of::<Self>(),
+            attributes: Self::ATTRIBUTES,
+        }
+    }
+
+    fn attribute(name: &str) -> Option<VertexAttribute> {
+        Self::layout().attribute(name)
+    }
+}
+
+/// Declares a vertex struct like `math::pod!` and implements `VertexLayout`
+/// for it, with one attribute per field named after it. Every field's type
+/// must implement `Attribute`, and the calling crate must depend on `math`.
+#[macro_export]
+macro_rules! vertex {
+    (
+        $(#[$attribute:meta])*
+        $visibility:vis struct $name:ident {
+            $($(#[$field_attribute:meta])* $field_visibility:vis $field:ident: $type:ty),* $(,)?
+        }
+    ) => {
+        ::math::pod! {
+            $(#[$attribute])*
+            $visibility struct $name {
+                $($(#[$field_attribute])* $field_visibility $field: $type),*
+            }
+        }
+
+        impl $crate::render::VertexLayout for $name {
+            const ATTRIBUTES: &'static [$crate::render::VertexAttribute] = &[$(
+                $crate::render::VertexAttribute {
+                    name: stringify!($field),
+                    format: <$type as $crate::render::Attribute>::FORMAT,
+                    offset: ::std::mem::offset_of!($name, $field),
+                }
+            ),*];
+        }
+    };
+}
+
+crate::vertex! {
+    #[derive(Clone, Copy)]
+    pub struct Vertex {
+        pub position: Vector<f32, 4>,
+        pub color: LinearRgba,
+    }
+}
+
+math::pod! {
+    #[derive(Clone, Copy)]
+    pub struct Render {
+        pub mvp: Matrix<f32, 4, 4>,
+    }
+}
+
+
+#[cfg(all(target_arch = "wasm32", web_sys_unstable_apis))]
 pub mod webgpu {
 const WEBGPU_MAX_BUFFER_SIZE: usize = 65536;
+//the default minUniformBufferOffsetAlignment, which every device supports
+const WEBGPU_UNIFORM_ALIGNMENT: usize = 256;
 
 use super::*;
         use js_sys::*;
         use web_sys::*;
 use wasm_bindgen::prelude::*;
 use math::prelude::*;
+use std::cell::RefCell;
 use std::cmp;
 use std::mem;
 use std::str;
@@ -65,13 +266,226 @@ pub struct WebGpu {
     color_texture_view: web_sys::GpuTextureView,
     depth_texture: web_sys::GpuTexture,
     depth_texture_view: web_sys::GpuTextureView,
-    pipeline: web_sys::GpuRenderPipeline,
+    shader_module: web_sys::GpuShaderModule,
+    pipeline_layout: web_sys::GpuPipelineLayout,
+    //one pipeline per vertex layout batches were made with
+    pipelines: RefCell<Vec<(VertexBufferLayout, web_sys::GpuRenderPipeline)>>,
+    bind_group_layout: web_sys::GpuBindGroupLayout,
     bind_group: web_sys::GpuBindGroup,
+    //one aligned slot per batch, picked with a dynamic offset
     render_buffer: web_sys::GpuBuffer,
+    render_capacity: usize,
     staging_buffer: web_sys::GpuBuffer,
+    clear_color: LinearRgba,
+}
+
+pub struct Batch {
+    pipeline: web_sys::GpuRenderPipeline,
+    vertex_buffer: web_sys::GpuBuffer,
+    index_buffer: Option<web_sys::GpuBuffer>,
+    index_format: web_sys::GpuIndexFormat,
+    vertex_count: usize,
+    index_count: Option<usize>,
+    model: Matrix<f32, 4, 4>,
+}
+
+impl Batch {
+    pub fn index_format(&self) -> web_sys::GpuIndexFormat {
+        self.index_format
+    }
+}
+
+impl Mesh for Batch {
+    fn vertex_count(&self) -> usize {
+        self.vertex_count
+    }
+
+    fn index_count(&self) -> Option<usize> {
+        self.index_count
+    }
+
+    fn model(&self) -> Matrix<f32, 4, 4> {
+        self.model
+    }
+
+    fn set_model(&mut self, model: Matrix<f32, 4, 4>) {
+        self.model = model;
+    }
+}
+
+fn vertex_format(format: VertexFormat) -> web_sys::GpuVertexFormat {
+    use web_sys::GpuVertexFormat;
+
+    match format {
+        VertexFormat::Float32 => GpuVertexFormat::Float32,
+        VertexFormat::Float32x2 => GpuVertexFormat::Float32x2,
+        VertexFormat::Float32x3 => GpuVertexFormat::Float32x3,
+        VertexFormat::Float32x4 => GpuVertexFormat::Float32x4,
+        VertexFormat::Uint32 => GpuVertexFormat::Uint32,
+        VertexFormat::Uint32x2 => GpuVertexFormat::Uint32x2,
+        VertexFormat::Uint32x3 => GpuVertexFormat::Uint32x3,
+        VertexFormat::Uint32x4 => GpuVertexFormat::Uint32x4,
+        VertexFormat::Sint32 => GpuVertexFormat::Sint32,
+        VertexFormat::Sint32x2 => GpuVertexFormat::Sint32x2,
+        VertexFormat::Sint32x3 => GpuVertexFormat::Sint32x3,
+        VertexFormat::Sint32x4 => GpuVertexFormat::Sint32x4,
+    }
+}
+
+impl WebGpu {
+    fn create_pipeline(
+        device: &web_sys::GpuDevice,
+        shader_module: &web_sys::GpuShaderModule,
+        pipeline_layout: &web_sys::GpuPipelineLayout,
+        layout: VertexBufferLayout,
+    ) -> web_sys::GpuRenderPipeline {
+        use js_sys::*;
+        use web_sys::*;
+
+        //batch_bytes rejects layouts missing an attribute the shader reads
+        let vertex_buffer_attributes = layout
+            .shader_locations()
+            .unwrap()
+            .into_iter()
+            .map(|(attribute, location)| {
+                GpuVertexAttribute::new(
+                    vertex_format(attribute.format),
+                    attribute.offset as _,
+                    location,
+                )
+            })
+            .collect::<Array>();
+
+        let mut vertex_buffer_layout = web_sys::GpuVertexBufferLayout::new(layout.stride as _, &vertex_buffer_attributes);
+        vertex_buffer_layout.step_mode(GpuVertexStepMode::Vertex);
+
+        let vertex_buffer_layouts = [
+            &vertex_buffer_layout
+        ].into_iter()
+        .collect::<Array>();
+
+        let mut vertex_state = GpuVertexState::new("vs", shader_module);
+        vertex_state.buffers(&vertex_buffer_layouts);
+
+        let mut depth_stencil_state = GpuDepthStencilState::new(GpuTextureFormat::Depth32float);
+        depth_stencil_state.depth_write_enabled(true);
+        depth_stencil_state.depth_compare(GpuCompareFunction::Less);
+
+        let color_state = GpuColorTargetState::new(GpuTextureFormat::Bgra8unorm);
+
+        let fragment_state_targets = [&color_state].into_iter().collect::<Array>();
+
+        let mut fragment_state =
+            GpuFragmentState::new("fs", shader_module, &fragment_state_targets);
+
+        let mut primitive_state = GpuPrimitiveState::new();
+        primitive_state.cull_mode(GpuCullMode::None);
+        primitive_state.front_face(GpuFrontFace::Cw);
+        primitive_state.topology(GpuPrimitiveTopology::TriangleList);
+
+        let mut pipeline_desc = GpuRenderPipelineDescriptor::new(pipeline_layout, &vertex_state);
+        pipeline_desc.depth_stencil(&depth_stencil_state);
+        pipeline_desc.fragment(&fragment_state);
+        pipeline_desc.primitive(&primitive_state);
+
+        device.create_render_pipeline(&pipeline_desc)
+    }
+
+    //the pipeline for a layout, created the first time it is batched
+    fn pipeline(&self, layout: VertexBufferLayout) -> web_sys::GpuRenderPipeline {
+        let mut pipelines = self.pipelines.borrow_mut();
+
+        if let Some((_, pipeline)) = pipelines.iter().find(|(existing, _)| *existing == layout) {
+            return pipeline.clone();
+        }
+
+        let pipeline = Self::create_pipeline(
+            &self.device,
+            &self.shader_module,
+            &self.pipeline_layout,
+            layout,
+        );
+
+        pipelines.push((layout, pipeline.clone()));
+
+        pipeline
+    }
+
+    fn create_render_buffer(
+        device: &web_sys::GpuDevice,
+        bind_group_layout: &web_sys::GpuBindGroupLayout,
+        capacity: usize,
+    ) -> (web_sys::GpuBuffer, web_sys::GpuBindGroup) {
+        use js_sys::*;
+        use web_sys::*;
+
+        const BINDING: u32 = 0;
+
+        let render_buffer_desc = GpuBufferDescriptor::new(
+            (WEBGPU_UNIFORM_ALIGNMENT * capacity) as _,
+            gpu_buffer_usage::COPY_DST | gpu_buffer_usage::UNIFORM,
+        );
+
+        let render_buffer = device.create_buffer(&render_buffer_desc);
+
+        //each draw sees one slot, not the whole buffer
+        let mut buffer_binding = GpuBufferBinding::new(&render_buffer);
+        buffer_binding.size(mem::size_of::<Render>() as _);
+
+        let bind_group_entry = GpuBindGroupEntry::new(BINDING, &buffer_binding);
+
+        let bind_group_entries = [&bind_group_entry].into_iter().collect::<Array>();
+
+        let bind_group_desc = GpuBindGroupDescriptor::new(&bind_group_entries, bind_group_layout);
+
+        let bind_group = device.create_bind_group(&bind_group_desc);
+
+        (render_buffer, bind_group)
+    }
+
+    //writes data to the start of recipient_buffer through the staging buffer
+    fn upload<T: Pod>(&self, data: &[T], recipient_buffer: &web_sys::GpuBuffer) {
+        use js_sys::*;
+
+        let mut bytes = as_bytes(data).to_vec();
+
+        while bytes.len() % 4 != 0 {
+            bytes.push(0);
+        }
+
+        for cursor in (0..bytes.len()).step_by(WEBGPU_MAX_BUFFER_SIZE) {
+            let start = cursor;
+            let length = cmp::min(WEBGPU_MAX_BUFFER_SIZE, bytes.len() - start);
+            let end = start + length;
+
+            self.queue.write_buffer_with_f64_and_u8_array(
+                &self.staging_buffer,
+                0.0,
+                &bytes[start..end],
+            );
+
+            let command_encoder = self.device.create_command_encoder();
+
+            command_encoder.copy_buffer_to_buffer_with_f64_and_f64_and_f64(
+                &self.staging_buffer,
+                0.0,
+                recipient_buffer,
+                start as _,
+                length as _,
+            );
+
+            let command_buffer = command_encoder.finish();
+
+            let command_submission = [&command_buffer].into_iter().collect::<Array>();
+
+            self.queue.submit(&command_submission);
+        }
+    }
 }
 
 impl Renderer for WebGpu {
+    type Batch = Batch;
+
     async fn new() -> Result<Self, ()> {
         use js_sys::*;
         use web_sys::*;
@@ -152,13 +566,6 @@ impl Renderer for WebGpu {
 
         let staging_buffer = device.create_buffer(&staging_buffer_desc);
 
-        let render_buffer_desc = GpuBufferDescriptor::new(
-            mem::size_of::<Render>() as _,
-            gpu_buffer_usage::COPY_DST | gpu_buffer_usage::UNIFORM,
-        );
-
-        let render_buffer = device.create_buffer(&render_buffer_desc);
-
         //shader module
         let shader_module_desc = GpuShaderModuleDescriptor::new(
             str::from_utf8(include_bytes!(concat!(
@@ -173,7 +580,8 @@ impl Renderer for WebGpu {
         //bind groups
         const BINDING: u32 = 0;
 
-        let buffer_binding_layout = GpuBufferBindingLayout::new();
+        let mut buffer_binding_layout = GpuBufferBindingLayout::new();
+        buffer_binding_layout.has_dynamic_offset(true);
 
         let mut bind_group_layout_entry =
             GpuBindGroupLayoutEntry::new(BINDING, gpu_shader_stage::VERTEX);
@@ -187,15 +595,10 @@ impl Renderer for WebGpu {
 
         let bind_group_layouts = [&bind_group_layout].into_iter().collect::<Array>();
 
-        let buffer_binding = GpuBufferBinding::new(&render_buffer);
-
-        let bind_group_entry = GpuBindGroupEntry::new(BINDING, &buffer_binding);
-
-        let bind_group_entries = [&bind_group_entry].into_iter().collect::<Array>();
-
-        let bind_group_desc = GpuBindGroupDescriptor::new(&bind_group_entries, &bind_group_layout);
+        let render_capacity = 1;
 
-        let bind_group = device.create_bind_group(&bind_group_desc);
+        let (render_buffer, bind_group) =
+            Self::create_render_buffer(&device, &bind_group_layout, render_capacity);
 
         //pipeline layout
         let pipeline_layout_desc = GpuPipelineLayoutDescriptor::new(&bind_group_layouts);
@@ -203,50 +606,14 @@ impl Renderer for WebGpu {
         let pipeline_layout = device.create_pipeline_layout(&pipeline_layout_desc);
 
         //pipeline
-        let position_vertex_attribute = web_sys::GpuVertexAttribute::new(GpuVertexFormat::Float32x4, 0.0, 0);
-
-        let vertex_buffer_stride = (
-   mem::size_of::<f32>() * 4 
-        ) as _;
-
-        let vertex_buffer_attributes = [
-            &position_vertex_attribute
-        ].into_iter()
-        .collect::<Array>();
-
-        let mut vertex_buffer_layout = web_sys::GpuVertexBufferLayout::new(vertex_buffer_stride, &vertex_buffer_attributes);
-        vertex_buffer_layout.step_mode(GpuVertexStepMode::Vertex);
-
-        let vertex_buffer_layouts = [
-            &vertex_buffer_layout
-        ].into_iter()
-        .collect::<Array>();
-
-        let mut vertex_state = GpuVertexState::new("vs", &shader_module);
-        vertex_state.buffers(&vertex_buffer_layouts);
-
-        let mut depth_stencil_state = GpuDepthStencilState::new(GpuTextureFormat::Depth32float);
-        depth_stencil_state.depth_write_enabled(true);
-        depth_stencil_state.depth_compare(GpuCompareFunction::Less);
-
-        let color_state = GpuColorTargetState::new(GpuTextureFormat::Bgra8unorm);
-
-        let fragment_state_targets = [&color_state].into_iter().collect::<Array>();
-
-        let mut fragment_state =
-            GpuFragmentState::new("fs", &shader_module, &fragment_state_targets);
-
-        let mut primitive_state = GpuPrimitiveState::new();
-        primitive_state.cull_mode(GpuCullMode::None);
-        primitive_state.front_face(GpuFrontFace::Cw);
-        primitive_state.topology(GpuPrimitiveTopology::TriangleList);
-
-        let mut pipeline_desc = GpuRenderPipelineDescriptor::new(&pipeline_layout, &vertex_state);
-        pipeline_desc.depth_stencil(&depth_stencil_state);
-        pipeline_desc.fragment(&fragment_state);
-        pipeline_desc.primitive(&primitive_state);
+        let pipeline = Self::create_pipeline(
+            &device,
+            &shader_module,
+            &pipeline_layout,
+            Vertex::layout(),
+        );
 
-        let pipeline = device.create_render_pipeline(&pipeline_desc);
+        let pipelines = RefCell::new(vec![(Vertex::layout(), pipeline)]);
 
         Ok(Self {
             resolution,
@@ -260,9 +627,14 @@ impl Renderer for WebGpu {
             depth_texture,
             depth_texture_view,
             render_buffer,
+            render_capacity,
             staging_buffer,
-            pipeline,
+            shader_module,
+            pipeline_layout,
+            pipelines,
+            bind_group_layout,
             bind_group,
+            clear_color: LinearRgba::WHITE,
         })
     }
 
@@ -304,7 +676,12 @@ impl Renderer for WebGpu {
         self.color_texture = self.context.get_current_texture();
         self.color_texture_view = self.color_texture.create_view();
 
-        let color_clear_value = unsafe { js_sys::Float64Array::view(&[1.0, 1.0, 1.0, 1.0]) };
+        //the canvas is not an srgb format, so it is cleared with encoded values
+        let color_clear_value = js_sys::Float64Array::from(
+            &Srgba::from(self.clear_color)
+                .to_array()
+                .map(|channel| channel as f64)[..],
+        );
 
         let mut color_attachment = GpuRenderPassColorAttachment::new(
             GpuLoadOp::Clear,
@@ -324,26 +701,33 @@ impl Renderer for WebGpu {
         let mut render_pass_desc = GpuRenderPassDescriptor::new(&color_attachments);
         render_pass_desc.depth_stencil_attachment(&depth_attachment);
 
-        
-                self.queue.write_buffer_with_f64_and_u8_array(
-                    &self.staging_buffer,
-                    0.0,
-                    to_bytes(&[render]),
-                );
-        
+        if batches.len() > self.render_capacity {
+            self.render_capacity = batches.len().next_power_of_two();
 
-        let command_encoder = self.device.create_command_encoder();
+            (self.render_buffer, self.bind_group) = Self::create_render_buffer(
+                &self.device,
+                &self.bind_group_layout,
+                self.render_capacity,
+            );
+        }
 
-        command_encoder.copy_buffer_to_buffer_with_f64_and_f64_and_f64(
-            &self.staging_buffer,
-            0.0,
-            &self.render_buffer,
-            0.0,
-            mem::size_of::<Render>() as _
-        );
+        //every batch gets its own mvp, in a slot aligned for dynamic offsets
+        let mut renders = vec![0u8; WEBGPU_UNIFORM_ALIGNMENT * batches.len()];
+        for (slot, batch) in renders
+            .chunks_exact_mut(WEBGPU_UNIFORM_ALIGNMENT)
+            .zip(batches)
+        {
+            let batch_render = Render {
+                mvp: render.mvp * batch.model,
+            };
+            slot[..mem::size_of::<Render>()].copy_from_slice(as_bytes(&[batch_render]));
+        }
+
+        self.upload(&renders, &self.render_buffer);
+
+        let command_encoder = self.device.create_command_encoder();
 
         let mut render_pass = command_encoder.begin_render_pass(&render_pass_desc);
-        render_pass.set_pipeline(&self.pipeline);
         render_pass.set_viewport(
             0.0,
             0.0,
@@ -353,10 +737,23 @@ impl Renderer for WebGpu {
             1.0,
         );
         render_pass.set_scissor_rect(0, 0, self.canvas.width(), self.canvas.height());
-        render_pass.set_bind_group(0, &self.bind_group);
-        render_pass.set_vertex_buffer(0, &batches[0].vertex_buffer);
-        render_pass.set_index_buffer(&batches[0].index_buffer, GpuIndexFormat::Uint16);
-        render_pass.draw_indexed(3);
+        for (slot, batch) in batches.iter().enumerate() {
+            let dynamic_offsets = [JsValue::from((WEBGPU_UNIFORM_ALIGNMENT * slot) as u32)]
+                .into_iter()
+                .collect::<Array>();
+
+            render_pass.set_pipeline(&batch.pipeline);
+            render_pass.set_bind_group_with_u32_sequence(0, &self.bind_group, &dynamic_offsets);
+            render_pass.set_vertex_buffer(0, &batch.vertex_buffer);
+
+            match (&batch.index_buffer, batch.index_count) {
+                (Some(index_buffer), Some(index_count)) => {
+                    render_pass.set_index_buffer(index_buffer, batch.index_format);
+                    render_pass.draw_indexed(index_count as _);
+                }
+                _ => render_pass.draw(batch.vertex_count as _),
+            }
+        }
         render_pass.end();
 
         let command_buffer = command_encoder.finish();
@@ -366,11 +763,21 @@ impl Renderer for WebGpu {
         self.queue.submit(&command_submission);
     }
 
-    fn batch(&self, vertices: &[Vertex], indices: &[Index]) -> Batch {
-        use js_sys::*;
+    fn batch_bytes(
+        &self,
+        layout: VertexBufferLayout,
+        vertices: &[u8],
+        indices: Option<&[Index]>,
+    ) -> Batch {
         use web_sys::*;
-        
-        let vertex_buffer_size = ((mem::size_of::<Vertex>() * vertices.len()) as f64 / 4.0).ceil() * 4.0;
+
+        if layout.shader_locations().is_none() {
+            panic!("vertices need a position and a color attribute");
+        }
+
+        let pipeline = self.pipeline(layout);
+
+        let vertex_buffer_size = (vertices.len() as f64 / 4.0).ceil() * 4.0;
         
         let vertex_buffer_desc = GpuBufferDescriptor::new(
             vertex_buffer_size,
@@ -379,63 +786,43 @@ impl Renderer for WebGpu {
 
         let vertex_buffer = self.device.create_buffer(&vertex_buffer_desc);
 
-        let index_buffer_size = ((mem::size_of::<Index>() * indices.len()) as f64 / 4.0).ceil() * 4.0;
-
-        let index_buffer_desc = GpuBufferDescriptor::new(
-            index_buffer_size,
-            gpu_buffer_usage::COPY_DST | gpu_buffer_usage::INDEX,
-        );
-
-        let index_buffer = self.device.create_buffer(&index_buffer_desc);
-        
-        fn upload_and_transfer<T: Copy>(
-            webgpu: &WebGpu,
-            data: &[T],
-            recipient_buffer: &web_sys::GpuBuffer,
-        ) {
-            let mut bytes = to_bytes(data).to_vec();
-                
-            while bytes.len() % 4 != 0 {
-                bytes.push(0);
-            }
-
-            for cursor in (0..bytes.len()).step_by(WEBGPU_MAX_BUFFER_SIZE) {
-                let start = cursor;
-                let length = cmp::min(WEBGPU_MAX_BUFFER_SIZE, bytes.len());
-                let end = start + length;
+        self.upload(vertices, &vertex_buffer);
 
-                webgpu.queue.write_buffer_with_f64_and_u8_array(
-                    &webgpu.staging_buffer,
-                    0.0,
-                    &bytes[start..end],
-                );
+        let index_buffer = indices.map(|indices| {
+            let index_buffer_size = ((mem::size_of::<Index>() * indices.len()) as f64 / 4.0).ceil() * 4.0;
 
-                let command_encoder = webgpu.device.create_command_encoder();
-
-                command_encoder.copy_buffer_to_buffer_with_f64_and_f64_and_f64(
-                    &webgpu.staging_buffer,
-                    0.0,
-                    recipient_buffer,
-                    start as _, 
-                    length as _,
-                );
+            let index_buffer_desc = GpuBufferDescriptor::new(
+                index_buffer_size,
+                gpu_buffer_usage::COPY_DST | gpu_buffer_usage::INDEX,
+            );
 
-                let command_buffer = command_encoder.finish();
+            let index_buffer = self.device.create_buffer(&index_buffer_desc);
 
-                let command_submission = [&command_buffer].into_iter().collect::<Array>();
+            self.upload(indices, &index_buffer);
 
-                webgpu.queue.submit(&command_submission);
-            }
-        }
+            index_buffer
+        });
 
-        upload_and_transfer(&self, vertices, &vertex_buffer);
-        upload_and_transfer(&self, indices, &index_buffer);
+        //follows Index, should it ever be widened
+        let index_format = match mem::size_of::<Index>() {
+            2 => GpuIndexFormat::Uint16,
+            _ => GpuIndexFormat::Uint32,
+        };
 
         Batch {
+            pipeline,
             vertex_buffer,
             index_buffer,
+            index_format,
+            vertex_count: vertices.len() / layout.stride,
+            index_count: indices.map(<[Index]>::len),
+            model: Matrix::identity(),
         }
     }
+
+    fn set_clear_color(&mut self, color: LinearRgba) {
+        self.clear_color = color;
+    }
 }
 }
 
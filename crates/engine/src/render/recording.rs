@@ -0,0 +1,312 @@
+//! A `Renderer` that draws nothing and logs every call instead, so code
+//! that renders can assert on what would be drawn.
+//!
+//! The log is a list of `Command`s, which reflect to json values the same
+//! way inspected components do.
+
+use super::*;
+use crate::reflect::{ReflectValue, Value};
+use std::cell::{Cell, Ref, RefCell};
+
+//clears to white like the other backends, being LinearRgba's default
+#[derive(Default)]
+pub struct RecordingRenderer {
+    //batch takes &self, like uploading to a gpu device would
+    commands: RefCell<Vec<Command>>,
+    next_batch: Cell<usize>,
+    clear_color: LinearRgba,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Batch {
+    id: usize,
+    vertex_count: usize,
+    index_count: Option<usize>,
+    model: Matrix<f32, 4, 4>,
+}
+
+impl Batch {
+    //batches are numbered from 0 in the order they were created
+    pub fn id(&self) -> usize {
+        self.id
+    }
+}
+
+impl Mesh for Batch {
+    fn vertex_count(&self) -> usize {
+        self.vertex_count
+    }
+
+    fn index_count(&self) -> Option<usize> {
+        self.index_count
+    }
+
+    fn model(&self) -> Matrix<f32, 4, 4> {
+        self.model
+    }
+
+    fn set_model(&mut self, model: Matrix<f32, 4, 4>) {
+        self.model = model;
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Command {
+    //buffer sizes are in bytes, before any padding a backend adds
+    Batch {
+        id: usize,
+        vertex_count: usize,
+        index_count: Option<usize>,
+        vertex_buffer_size: usize,
+        index_buffer_size: usize,
+        //the vertex layout's attribute names, in shader location order
+        attributes: Vec<String>,
+    },
+    Render {
+        mvp: Matrix<f32, 4, 4>,
+        clear_color: LinearRgba,
+        draws: Vec<Draw>,
+    },
+    SetClearColor(LinearRgba),
+}
+
+//one batch drawn by a render, in drawing order
+#[derive(Clone, Debug, PartialEq)]
+pub struct Draw {
+    pub batch: usize,
+    pub vertex_count: usize,
+    //none for a non-indexed draw
+    pub index_count: Option<usize>,
+    pub model: Matrix<f32, 4, 4>,
+}
+
+impl RecordingRenderer {
+    pub fn commands(&self) -> Ref<'_, [Command]> {
+        Ref::map(self.commands.borrow(), Vec::as_slice)
+    }
+
+    //empties the log, returning what it held. batch ids keep counting
+    pub fn take(&self) -> Vec<Command> {
+        self.commands.take()
+    }
+
+    //every render in the log, as (mvp, draws)
+    pub fn renders(&self) -> Vec<(Matrix<f32, 4, 4>, Vec<Draw>)> {
+        self.commands
+            .borrow()
+            .iter()
+            .filter_map(|command| match command {
+                Command::Render { mvp, draws, .. } => Some((*mvp, draws.clone())),
+                _ => None,
+            })
+            .collect()
+    }
+
+    //the log as a json array
+    pub fn to_value(&self) -> Value {
+        Value::Array(
+            self.commands
+                .borrow()
+                .iter()
+                .map(Command::to_value)
+                .collect(),
+        )
+    }
+}
+
+impl Renderer for RecordingRenderer {
+    type Batch = Batch;
+
+    async fn new() -> Result<Self, ()> {
+        Ok(Self::default())
+    }
+
+    fn render(&mut self, render: Render, batches: &[&Batch]) {
+        let draws = batches
+            .iter()
+            .map(|batch| Draw {
+                batch: batch.id,
+                vertex_count: batch.vertex_count,
+                index_count: batch.index_count,
+                model: batch.model,
+            })
+            .collect();
+
+        self.commands.get_mut().push(Command::Render {
+            mvp: render.mvp,
+            clear_color: self.clear_color,
+            draws,
+        });
+    }
+
+    fn batch_bytes(
+        &self,
+        layout: VertexBufferLayout,
+        vertices: &[u8],
+        indices: Option<&[Index]>,
+    ) -> Batch {
+        let id = self.next_batch.get();
+        self.next_batch.set(id + 1);
+
+        let vertex_count = vertices.len() / layout.stride;
+
+        self.commands.borrow_mut().push(Command::Batch {
+            id,
+            vertex_count,
+            index_count: indices.map(<[Index]>::len),
+            vertex_buffer_size: vertices.len(),
+            index_buffer_size: indices.map_or(0, mem::size_of_val),
+            attributes: layout
+                .attributes
+                .iter()
+                .map(|attribute| attribute.name.to_owned())
+                .collect(),
+        });
+
+        Batch {
+            id,
+            vertex_count,
+            index_count: indices.map(<[Index]>::len),
+            model: Matrix::identity(),
+        }
+    }
+
+    fn set_clear_color(&mut self, color: LinearRgba) {
+        self.clear_color = color;
+        self.commands.get_mut().push(Command::SetClearColor(color));
+    }
+}
+
+fn field<T: ReflectValue>(fields: &[(String, Value)], name: &str) -> Option<T> {
+    let (_, value) = fields.iter().find(|(field, _)| field == name)?;
+    T::from_value(value)
+}
+
+//an index count of none is null, which is still a present field
+fn index_count(fields: &[(String, Value)]) -> Option<Option<usize>> {
+    match fields.iter().find(|(field, _)| field == "index_count")? {
+        (_, Value::Null) => Some(None),
+        (_, value) => usize::from_value(value).map(Some),
+    }
+}
+
+fn index_count_value(index_count: Option<usize>) -> Value {
+    index_count.map_or(Value::Null, |count| count.to_value())
+}
+
+//objects tagged with a "command" field, like {"command":"render",...}
+impl ReflectValue for Command {
+    fn to_value(&self) -> Value {
+        let fields: Vec<(&str, Value)> = match self {
+            Command::Batch {
+                id,
+                vertex_count,
+                index_count,
+                vertex_buffer_size,
+                index_buffer_size,
+                attributes,
+            } => vec![
+                ("command", Value::String("batch".to_owned())),
+                ("id", id.to_value()),
+                ("vertex_count", vertex_count.to_value()),
+                ("index_count", index_count_value(*index_count)),
+                ("vertex_buffer_size", vertex_buffer_size.to_value()),
+                ("index_buffer_size", index_buffer_size.to_value()),
+                (
+                    "attributes",
+                    Value::Array(attributes.iter().map(String::to_value).collect()),
+                ),
+            ],
+            Command::Render {
+                mvp,
+                clear_color,
+                draws,
+            } => vec![
+                ("command", Value::String("render".to_owned())),
+                ("mvp", mvp.to_value()),
+                ("clear_color", clear_color.to_value()),
+                (
+                    "draws",
+                    Value::Array(draws.iter().map(Draw::to_value).collect()),
+                ),
+            ],
+            Command::SetClearColor(color) => vec![
+                ("command", Value::String("set_clear_color".to_owned())),
+                ("color", color.to_value()),
+            ],
+        };
+
+        Value::Object(
+            fields
+                .into_iter()
+                .map(|(name, value)| (name.to_owned(), value))
+                .collect(),
+        )
+    }
+
+    fn from_value(value: &Value) -> Option<Self> {
+        let Value::Object(fields) = value else { None? };
+
+        let command = match field::<String>(fields, "command")?.as_str() {
+            "batch" => {
+                let Some((_, Value::Array(attributes))) =
+                    fields.iter().find(|(name, _)| name == "attributes")
+                else {
+                    None?
+                };
+                Command::Batch {
+                    id: field(fields, "id")?,
+                    vertex_count: field(fields, "vertex_count")?,
+                    index_count: index_count(fields)?,
+                    vertex_buffer_size: field(fields, "vertex_buffer_size")?,
+                    index_buffer_size: field(fields, "index_buffer_size")?,
+                    attributes: attributes
+                        .iter()
+                        .map(String::from_value)
+                        .collect::<Option<_>>()?,
+                }
+            }
+            "render" => {
+                let Some((_, Value::Array(draws))) =
+                    fields.iter().find(|(name, _)| name == "draws")
+                else {
+                    None?
+                };
+                Command::Render {
+                    mvp: field(fields, "mvp")?,
+                    clear_color: field(fields, "clear_color")?,
+                    draws: draws.iter().map(Draw::from_value).collect::<Option<_>>()?,
+                }
+            }
+            "set_clear_color" => Command::SetClearColor(field(fields, "color")?),
+            _ => None?,
+        };
+
+        Some(command)
+    }
+}
+
+impl ReflectValue for Draw {
+    fn to_value(&self) -> Value {
+        Value::Object(vec![
+            ("batch".to_owned(), self.batch.to_value()),
+            ("vertex_count".to_owned(), self.vertex_count.to_value()),
+            (
+                "index_count".to_owned(),
+                index_count_value(self.index_count),
+            ),
+            ("model".to_owned(), self.model.to_value()),
+        ])
+    }
+
+    fn from_value(value: &Value) -> Option<Self> {
+        let Value::Object(fields) = value else { None? };
+
+        Some(Draw {
+            batch: field(fields, "batch")?,
+            vertex_count: field(fields, "vertex_count")?,
+            index_count: index_count(fields)?,
+            model: field(fields, "model")?,
+        })
+    }
+}
@@ -0,0 +1,432 @@
+//! A CPU rasterizer implementing `Renderer` without a browser or GPU, so
+//! rendering can be tested on any machine.
+//!
+//! It follows the WebGPU pipeline `webgpu::WebGpu` sets up: triangle lists
+//! with no culling, `Render::mvp * position` in clip space, clipping to
+//! `0 <= z <= w`, a `Less` depth test against a buffer cleared to 1,
+//! perspective correct colors and sRGB encoded 8 bit output. Pixels are
+//! sampled at their centers with the top-left fill rule, so shared edges
+//! are drawn exactly once.
+//!
+//! Batches of any `VertexLayout` are read by attribute name: a `position`
+//! is required and a `color` is optional, defaulting to white. Other
+//! attributes are uploaded by the WebGPU backend but unused by its shader,
+//! so they are ignored here too.
+
+#![allow(clippy::needless_range_loop)]
+
+use super::*;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+pub const DEFAULT_RESOLUTION: (u32, u32) = (256, 256);
+
+pub struct Software {
+    resolution: (u32, u32),
+    //rgba8, row by row from the top left
+    color: Vec<[u8; 4]>,
+    depth: Vec<f32>,
+    clear_color: LinearRgba,
+}
+
+pub struct Batch {
+    vertices: Vec<Vertex>,
+    indices: Option<Vec<Index>>,
+    model: Matrix<f32, 4, 4>,
+}
+
+impl Mesh for Batch {
+    fn vertex_count(&self) -> usize {
+        self.vertices.len()
+    }
+
+    fn index_count(&self) -> Option<usize> {
+        self.indices.as_ref().map(Vec::len)
+    }
+
+    fn model(&self) -> Matrix<f32, 4, 4> {
+        self.model
+    }
+
+    fn set_model(&mut self, model: Matrix<f32, 4, 4>) {
+        self.model = model;
+    }
+}
+
+//a vertex after the vertex stage
+#[derive(Clone, Copy)]
+struct Clip {
+    position: Vector<f32, 4>,
+    color: [f32; 4],
+}
+
+impl Clip {
+    fn lerp(self, other: Self, t: f32) -> Self {
+        let mut color = self.color;
+        for i in 0..4 {
+            color[i] += (other.color[i] - color[i]) * t;
+        }
+        Self {
+            position: self.position + (other.position - self.position) * t,
+            color,
+        }
+    }
+}
+
+//a clipped vertex in pixels, with depth in 0..1
+#[derive(Clone, Copy)]
+struct Screen {
+    x: f32,
+    y: f32,
+    depth: f32,
+    //1 / w, interpolated linearly in screen space unlike the colors
+    inverse_w: f32,
+    //color / w
+    color: [f32; 4],
+}
+
+impl Software {
+    pub fn with_resolution(width: u32, height: u32) -> Self {
+        let mut software = Self {
+            resolution: (width, height),
+            color: vec![],
+            depth: vec![],
+            clear_color: LinearRgba::WHITE,
+        };
+        software.set_resolution(width, height);
+        software
+    }
+
+    pub fn resolution(&self) -> (u32, u32) {
+        self.resolution
+    }
+
+    //the framebuffer is cleared to the clear color
+    pub fn set_resolution(&mut self, width: u32, height: u32) {
+        let pixels = width as usize * height as usize;
+        self.resolution = (width, height);
+        self.color = vec![Srgba::from(self.clear_color).to_u8_array(); pixels];
+        self.depth = vec![1.0; pixels];
+    }
+
+    //the framebuffer as sRGB encoded rgba8 pixels, row by row from the top
+    pub fn pixels(&self) -> &[[u8; 4]] {
+        &self.color
+    }
+
+    pub fn pixel(&self, x: u32, y: u32) -> Srgba {
+        Srgba::from_u8_array(self.color[(y * self.resolution.0 + x) as usize])
+    }
+
+    pub fn depth(&self, x: u32, y: u32) -> f32 {
+        self.depth[(y * self.resolution.0 + x) as usize]
+    }
+
+    pub fn to_png(&self) -> Vec<u8> {
+        let (width, height) = self.resolution;
+        png(width, height, &self.color)
+    }
+
+    pub fn save_png(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        fs::write(path, self.to_png())
+    }
+
+    fn draw(&mut self, triangle: [Clip; 3]) {
+        let polygon = clip(triangle.to_vec());
+        if polygon.len() < 3 {
+            return;
+        }
+
+        let screen = polygon
+            .into_iter()
+            .map(|vertex| self.viewport(vertex))
+            .collect::<Vec<_>>();
+
+        //the clipped polygon is convex, so it fans out from its first vertex
+        for i in 1..screen.len() - 1 {
+            self.rasterize([screen[0], screen[i], screen[i + 1]]);
+        }
+    }
+
+    fn viewport(&self, vertex: Clip) -> Screen {
+        let (width, height) = (self.resolution.0 as f32, self.resolution.1 as f32);
+        let [x, y, z, w] = *vertex.position;
+        let inverse_w = 1.0 / w;
+        Screen {
+            x: (x * inverse_w + 1.0) * 0.5 * width,
+            //y points up in clip space and down the framebuffer
+            y: (1.0 - y * inverse_w) * 0.5 * height,
+            depth: z * inverse_w,
+            inverse_w,
+            color: vertex.color.map(|channel| channel * inverse_w),
+        }
+    }
+
+    fn rasterize(&mut self, [a, b, c]: [Screen; 3]) {
+        let area = edge(a, b, c.x, c.y);
+        if area == 0.0 || !area.is_finite() {
+            return;
+        }
+
+        //with y down, a positive area is clockwise on screen. the edges
+        //are flipped for the other winding so inside is always positive
+        let (b, c) = if area < 0.0 { (c, b) } else { (b, c) };
+        let area = area.abs();
+
+        let (width, height) = self.resolution;
+        let min_x = a.x.min(b.x).min(c.x).floor().max(0.0) as u32;
+        let min_y = a.y.min(b.y).min(c.y).floor().max(0.0) as u32;
+        let max_x = (a.x.max(b.x).max(c.x).ceil().max(0.0) as u32).min(width);
+        let max_y = (a.y.max(b.y).max(c.y).ceil().max(0.0) as u32).min(height);
+
+        let edges = [(b, c), (c, a), (a, b)];
+        let owns = edges.map(|(from, to)| top_left(from, to));
+
+        for y in min_y..max_y {
+            for x in min_x..max_x {
+                let (px, py) = (x as f32 + 0.5, y as f32 + 0.5);
+
+                let mut weights = [0.0; 3];
+                let mut inside = true;
+                for i in 0..3 {
+                    let (from, to) = edges[i];
+                    weights[i] = edge(from, to, px, py);
+                    inside &= weights[i] > 0.0 || (weights[i] == 0.0 && owns[i]);
+                }
+                if !inside {
+                    continue;
+                }
+                let weights = weights.map(|weight| weight / area);
+
+                let depth = weights[0] * a.depth + weights[1] * b.depth + weights[2] * c.depth;
+                let index = (y * width + x) as usize;
+                //the clipped polygon only strays out of range by rounding
+                if depth >= self.depth[index] || !(0.0..=1.0).contains(&depth) {
+                    continue;
+                }
+
+                let inverse_w =
+                    weights[0] * a.inverse_w + weights[1] * b.inverse_w + weights[2] * c.inverse_w;
+                let mut color = [0.0; 4];
+                for i in 0..4 {
+                    color[i] = (weights[0] * a.color[i]
+                        + weights[1] * b.color[i]
+                        + weights[2] * c.color[i])
+                        / inverse_w;
+                }
+
+                let [red, green, blue, alpha] = color;
+                let color = LinearRgba::new(red, green, blue, alpha);
+                self.color[index] = Srgba::from(color).to_u8_array();
+                self.depth[index] = depth;
+            }
+        }
+    }
+}
+
+//twice the signed area of from, to and the point, positive when the point
+//is clockwise of the edge on screen
+fn edge(from: Screen, to: Screen, x: f32, y: f32) -> f32 {
+    (to.x - from.x) * (y - from.y) - (to.y - from.y) * (x - from.x)
+}
+
+//whether pixels exactly on an edge belong to this triangle. with y down
+//and clockwise winding, top edges run right and left edges run up
+fn top_left(from: Screen, to: Screen) -> bool {
+    let top = from.y == to.y && to.x > from.x;
+    let left = to.y < from.y;
+    top || left
+}
+
+//sutherland-hodgman against the near and far planes, 0 <= z <= w. x and
+//y are left to the pixel bounds, so only depth can push w to zero
+fn clip(mut polygon: Vec<Clip>) -> Vec<Clip> {
+    let planes: [fn(Vector<f32, 4>) -> f32; 2] =
+        [|position| position[2], |position| position[3] - position[2]];
+
+    for distance in planes {
+        let mut clipped = Vec::with_capacity(polygon.len() + 1);
+        for i in 0..polygon.len() {
+            let current = polygon[i];
+            let next = polygon[(i + 1) % polygon.len()];
+            let (from, to) = (distance(current.position), distance(next.position));
+
+            if from >= 0.0 {
+                clipped.push(current);
+            }
+            if (from >= 0.0) != (to >= 0.0) {
+                clipped.push(current.lerp(next, from / (from - to)));
+            }
+        }
+        polygon = clipped;
+    }
+
+    polygon
+}
+
+impl Renderer for Software {
+    type Batch = Batch;
+
+    async fn new() -> Result<Self, ()> {
+        let (width, height) = DEFAULT_RESOLUTION;
+        Ok(Self::with_resolution(width, height))
+    }
+
+    fn render(&mut self, render: Render, batches: &[&Batch]) {
+        self.color.fill(Srgba::from(self.clear_color).to_u8_array());
+        self.depth.fill(1.0);
+
+        for batch in batches {
+            let mvp = render.mvp * batch.model;
+            let clip = batch
+                .vertices
+                .iter()
+                .map(|vertex| Clip {
+                    position: mvp * vertex.position,
+                    color: vertex.color.to_array(),
+                })
+                .collect::<Vec<_>>();
+
+            match &batch.indices {
+                Some(indices) => {
+                    for triangle in indices.chunks_exact(3) {
+                        self.draw([0, 1, 2].map(|i| clip[triangle[i] as usize]));
+                    }
+                }
+                None => {
+                    for triangle in clip.chunks_exact(3) {
+                        self.draw([triangle[0], triangle[1], triangle[2]]);
+                    }
+                }
+            }
+        }
+    }
+
+    fn batch_bytes(
+        &self,
+        layout: VertexBufferLayout,
+        vertices: &[u8],
+        indices: Option<&[Index]>,
+    ) -> Batch {
+        let position = layout
+            .attribute("position")
+            .expect("vertices need a position attribute");
+        let color = layout.attribute("color");
+
+        let vertices = vertices
+            .chunks_exact(layout.stride)
+            .map(|vertex| Vertex {
+                position: Vector::new(read(vertex, position)),
+                color: color.map_or(LinearRgba::WHITE, |color| {
+                    let [r, g, b, a] = read(vertex, color);
+                    LinearRgba::new(r, g, b, a)
+                }),
+            })
+            .collect();
+
+        Batch {
+            vertices,
+            indices: indices.map(<[Index]>::to_vec),
+            model: Matrix::identity(),
+        }
+    }
+
+    fn set_clear_color(&mut self, color: LinearRgba) {
+        self.clear_color = color;
+    }
+}
+
+//an attribute of one vertex's bytes as floats, with missing components
+//filled in from (0, 0, 0, 1) like the gpu does
+fn read(vertex: &[u8], attribute: VertexAttribute) -> [f32; 4] {
+    let mut components = [0.0, 0.0, 0.0, 1.0];
+    let bytes = &vertex[attribute.offset..attribute.offset + attribute.format.size()];
+    for (component, bytes) in components.iter_mut().zip(bytes.chunks_exact(4)) {
+        let bytes = bytes.try_into().unwrap();
+        *component = match attribute.format {
+            VertexFormat::Float32
+            | VertexFormat::Float32x2
+            | VertexFormat::Float32x3
+            | VertexFormat::Float32x4 => f32::from_ne_bytes(bytes),
+            VertexFormat::Uint32
+            | VertexFormat::Uint32x2
+            | VertexFormat::Uint32x3
+            | VertexFormat::Uint32x4 => u32::from_ne_bytes(bytes) as f32,
+            _ => i32::from_ne_bytes(bytes) as f32,
+        };
+    }
+    components
+}
+
+//an 8 bit rgba png, stored uncompressed so it needs no deflate encoder.
+//the output only depends on the pixels, so goldens can compare bytes
+fn png(width: u32, height: u32, pixels: &[[u8; 4]]) -> Vec<u8> {
+    //every row starts with filter type 0, none
+    let mut raw = Vec::with_capacity(pixels.len() * 4 + height as usize);
+    for row in pixels.chunks(width.max(1) as usize) {
+        raw.push(0);
+        raw.extend(row.iter().flatten());
+    }
+
+    //zlib around deflate's stored blocks, which hold up to 65535 bytes
+    let mut zlib = vec![0x78, 0x01];
+    let mut blocks = raw.chunks(65535).peekable();
+    if blocks.peek().is_none() {
+        zlib.extend([1, 0, 0, 0xff, 0xff]);
+    }
+    while let Some(block) = blocks.next() {
+        let last = blocks.peek().is_none() as u8;
+        let length = block.len() as u16;
+        zlib.push(last);
+        zlib.extend(length.to_le_bytes());
+        zlib.extend((!length).to_le_bytes());
+        zlib.extend(block);
+    }
+    zlib.extend(adler32(&raw).to_be_bytes());
+
+    let mut header = vec![];
+    header.extend(width.to_be_bytes());
+    header.extend(height.to_be_bytes());
+    //bit depth 8, color type rgba, default compression, filtering and no interlacing
+    header.extend([8, 6, 0, 0, 0]);
+
+    let mut png = vec![0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n'];
+    chunk(&mut png, b"IHDR", &header);
+    chunk(&mut png, b"IDAT", &zlib);
+    chunk(&mut png, b"IEND", &[]);
+    png
+}
+
+fn chunk(png: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    png.extend((data.len() as u32).to_be_bytes());
+    let start = png.len();
+    png.extend(kind);
+    png.extend(data);
+    let crc = crc32(&png[start..]);
+    png.extend(crc.to_be_bytes());
+}
+
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0_u32;
+    for byte in data {
+        crc ^= *byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xedb88320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+fn adler32(data: &[u8]) -> u32 {
+    let (mut a, mut b) = (1_u32, 0_u32);
+    for byte in data {
+        a = (a + *byte as u32) % 65521;
+        b = (b + a) % 65521;
+    }
+    (b << 16) | a
+}
@@ -16,9 +16,7 @@ pub struct Model {
     batch_settings: BatchSettings,
     vertices: Vertices,
     indices: Option<Indices>,
-    position: Vec<f32, 3>,
-    rotation: Vec<f32, 3>,
-    scale: Vec<f32, 3>,
+    transform: Transform<f32>,
 }
 
 pub struct BatchSettings {
@@ -1,4 +1,6 @@
+use std::alloc;
 use std::mem;
+use std::ops;
 use std::ptr;
 use std::slice;
 
@@ -41,6 +43,10 @@ pub unsafe fn ref_bytes<T>(data: &[u8]) -> &T {
         panic!("byte slice must be same size as type");
     }
 
+    if !data.as_ptr().cast::<T>().is_aligned() {
+        panic!("byte slice must be aligned for type");
+    }
+
     (data.as_ptr() as *const _ as *const T).as_ref().unwrap()
 }
 
@@ -49,5 +55,142 @@ pub unsafe fn mut_bytes<T>(data: &mut [u8]) -> &mut T {
         panic!("byte slice must be same size as type");
     }
 
+    if !data.as_ptr().cast::<T>().is_aligned() {
+        panic!("byte slice must be aligned for type");
+    }
+
     (data.as_mut_ptr() as *mut _ as *mut T).as_mut().unwrap()
 }
+
+//a growable byte buffer like Vec<u8>, whose allocation is aligned to align
+pub struct AlignedBytes {
+    ptr: ptr::NonNull<u8>,
+    len: usize,
+    capacity: usize,
+    align: usize,
+}
+
+//SAFETY: the buffer owns its allocation, like Vec<u8>
+unsafe impl Send for AlignedBytes {}
+unsafe impl Sync for AlignedBytes {}
+
+impl AlignedBytes {
+    pub fn new(align: usize) -> Self {
+        if !align.is_power_of_two() {
+            panic!("alignment must be a power of two");
+        }
+
+        Self {
+            ptr: Self::dangling(align),
+            len: 0,
+            capacity: 0,
+            align,
+        }
+    }
+
+    pub fn with_capacity(align: usize, capacity: usize) -> Self {
+        let mut bytes = Self::new(align);
+        bytes.set_capacity(capacity);
+        bytes
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn reserve(&mut self, additional: usize) {
+        let required = self
+            .len
+            .checked_add(additional)
+            .expect("capacity overflow");
+
+        if required > self.capacity {
+            self.set_capacity(required.max(self.capacity * 2));
+        }
+    }
+
+    pub fn extend_from_slice(&mut self, bytes: &[u8]) {
+        self.reserve(bytes.len());
+
+        //SAFETY: reserve made room for bytes after the first len bytes
+        unsafe {
+            ptr::copy_nonoverlapping(bytes.as_ptr(), self.ptr.as_ptr().add(self.len), bytes.len())
+        };
+
+        self.len += bytes.len();
+    }
+
+    pub fn truncate(&mut self, len: usize) {
+        self.len = self.len.min(len);
+    }
+
+    pub fn shrink_to_fit(&mut self) {
+        if self.capacity > self.len {
+            self.set_capacity(self.len);
+        }
+    }
+
+    //an aligned pointer standing in for an empty allocation
+    fn dangling(align: usize) -> ptr::NonNull<u8> {
+        ptr::NonNull::new(ptr::without_provenance_mut(align)).unwrap()
+    }
+
+    fn layout(&self, capacity: usize) -> alloc::Layout {
+        alloc::Layout::from_size_align(capacity, self.align).expect("capacity overflow")
+    }
+
+    fn set_capacity(&mut self, capacity: usize) {
+        let old_layout = self.layout(self.capacity);
+        let new_layout = self.layout(capacity);
+
+        let ptr = match (self.capacity, capacity) {
+            (_, 0) => {
+                if self.capacity != 0 {
+                    //SAFETY: ptr was allocated with old_layout
+                    unsafe { alloc::dealloc(self.ptr.as_ptr(), old_layout) };
+                }
+                Self::dangling(self.align)
+            }
+            //SAFETY: new_layout has a nonzero size
+            (0, _) => ptr::NonNull::new(unsafe { alloc::alloc(new_layout) })
+                .unwrap_or_else(|| alloc::handle_alloc_error(new_layout)),
+            //SAFETY: ptr was allocated with old_layout and capacity is nonzero
+            _ => ptr::NonNull::new(unsafe {
+                alloc::realloc(self.ptr.as_ptr(), old_layout, capacity)
+            })
+            .unwrap_or_else(|| alloc::handle_alloc_error(new_layout)),
+        };
+
+        self.ptr = ptr;
+        self.capacity = capacity;
+        self.len = self.len.min(capacity);
+    }
+}
+
+impl Default for AlignedBytes {
+    fn default() -> Self {
+        Self::new(1)
+    }
+}
+
+impl Drop for AlignedBytes {
+    fn drop(&mut self) {
+        self.set_capacity(0);
+    }
+}
+
+impl ops::Deref for AlignedBytes {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        //SAFETY: the first len bytes of the allocation are initialized
+        unsafe { slice::from_raw_parts(self.ptr.as_ptr(), self.len) }
+    }
+}
+
+impl ops::DerefMut for AlignedBytes {
+    fn deref_mut(&mut self) -> &mut [u8] {
+        //SAFETY: the first len bytes of the allocation are initialized
+        unsafe { slice::from_raw_parts_mut(self.ptr.as_ptr(), self.len) }
+    }
+}
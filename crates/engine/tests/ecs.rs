@@ -0,0 +1,407 @@
+//! `World` tests, including a property test against a simple model.
+
+#![cfg(not(target_arch = "wasm32"))]
+
+use engine::ecs::*;
+use proptest::prelude::*;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+enum Kind {
+    Small,
+    Medium,
+    Large,
+    Text,
+    Tracked,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Value {
+    Small(u8),
+    Medium(u32),
+    Large([u64; 3]),
+    Text(String),
+    Tracked,
+}
+
+impl Value {
+    fn kind(&self) -> Kind {
+        match self {
+            Value::Small(_) => Kind::Small,
+            Value::Medium(_) => Kind::Medium,
+            Value::Large(_) => Kind::Large,
+            Value::Text(_) => Kind::Text,
+            Value::Tracked => Kind::Tracked,
+        }
+    }
+}
+
+//counts live copies so that leaks and double drops show up
+struct Tracked {
+    _live: Rc<()>,
+}
+
+#[derive(Clone, Debug)]
+enum Operation {
+    Spawn,
+    Despawn(usize),
+    Add(usize, Value),
+    Remove(usize, Kind),
+}
+
+fn kind() -> impl Strategy<Value = Kind> {
+    prop_oneof![
+        Just(Kind::Small),
+        Just(Kind::Medium),
+        Just(Kind::Large),
+        Just(Kind::Text),
+        Just(Kind::Tracked),
+    ]
+}
+
+fn value() -> impl Strategy<Value = Value> {
+    prop_oneof![
+        any::<u8>().prop_map(Value::Small),
+        any::<u32>().prop_map(Value::Medium),
+        any::<[u64; 3]>().prop_map(Value::Large),
+        "[a-z]{0,12}".prop_map(Value::Text),
+        Just(Value::Tracked),
+    ]
+}
+
+fn operation() -> impl Strategy<Value = Operation> {
+    prop_oneof![
+        1 => Just(Operation::Spawn),
+        1 => any::<usize>().prop_map(Operation::Despawn),
+        4 => (any::<usize>(), value()).prop_map(|(i, v)| Operation::Add(i, v)),
+        2 => (any::<usize>(), kind()).prop_map(|(i, k)| Operation::Remove(i, k)),
+    ]
+}
+
+#[derive(Default)]
+struct Model {
+    entities: HashMap<Entity, HashMap<Kind, Value>>,
+    alive: Vec<Entity>,
+}
+
+impl Model {
+    fn pick(&self, index: usize) -> Option<Entity> {
+        if self.alive.is_empty() {
+            None?
+        }
+        Some(self.alive[index % self.alive.len()])
+    }
+}
+
+fn add(world: &mut World, tracker: &Rc<()>, entity: Entity, value: &Value) {
+    match value.clone() {
+        Value::Small(v) => world.add(entity, v),
+        Value::Medium(v) => world.add(entity, v),
+        Value::Large(v) => world.add(entity, v),
+        Value::Text(v) => world.add(entity, v),
+        Value::Tracked => world.add(
+            entity,
+            Tracked {
+                _live: tracker.clone(),
+            },
+        ),
+    }
+}
+
+fn remove(world: &mut World, entity: Entity, kind: Kind) -> Option<Value> {
+    match kind {
+        Kind::Small => world.remove::<u8>(entity).map(Value::Small),
+        Kind::Medium => world.remove::<u32>(entity).map(Value::Medium),
+        Kind::Large => world.remove::<[u64; 3]>(entity).map(Value::Large),
+        Kind::Text => world.remove::<String>(entity).map(Value::Text),
+        Kind::Tracked => world.remove::<Tracked>(entity).map(|_| Value::Tracked),
+    }
+}
+
+fn get(world: &World, entity: Entity, kind: Kind) -> Option<Value> {
+    match kind {
+        Kind::Small => world.get::<u8>(entity).copied().map(Value::Small),
+        Kind::Medium => world.get::<u32>(entity).copied().map(Value::Medium),
+        Kind::Large => world.get::<[u64; 3]>(entity).copied().map(Value::Large),
+        Kind::Text => world.get::<String>(entity).cloned().map(Value::Text),
+        Kind::Tracked => world.get::<Tracked>(entity).map(|_| Value::Tracked),
+    }
+}
+
+const KINDS: [Kind; 5] = [
+    Kind::Small,
+    Kind::Medium,
+    Kind::Large,
+    Kind::Text,
+    Kind::Tracked,
+];
+
+proptest! {
+    #[test]
+    fn world_matches_model(operations in prop::collection::vec(operation(), 1..200)) {
+        let tracker = Rc::new(());
+
+        {
+            let mut world = World::new();
+            let mut model = Model::default();
+
+            for operation in operations {
+                match operation {
+                    Operation::Spawn => {
+                        let entity = world.spawn();
+                        prop_assert!(!model.entities.contains_key(&entity));
+                        model.entities.insert(entity, HashMap::new());
+                        model.alive.push(entity);
+                    }
+                    Operation::Despawn(index) => {
+                        let Some(entity) = model.pick(index) else {
+                            continue;
+                        };
+                        world.despawn(entity);
+                        model.entities.remove(&entity);
+                        model.alive.retain(|&alive| alive != entity);
+                    }
+                    Operation::Add(index, value) => {
+                        let Some(entity) = model.pick(index) else {
+                            continue;
+                        };
+                        add(&mut world, &tracker, entity, &value);
+                        model.entities.get_mut(&entity).unwrap().insert(value.kind(), value);
+                    }
+                    Operation::Remove(index, kind) => {
+                        let Some(entity) = model.pick(index) else {
+                            continue;
+                        };
+                        let removed = remove(&mut world, entity, kind);
+                        let expected = model.entities.get_mut(&entity).unwrap().remove(&kind);
+                        prop_assert_eq!(removed, expected);
+                    }
+                }
+
+                #[cfg(feature = "ecs-validate")]
+                world.validate();
+
+                for (&entity, components) in &model.entities {
+                    for kind in KINDS {
+                        prop_assert_eq!(get(&world, entity, kind), components.get(&kind).cloned());
+                    }
+                }
+            }
+
+            let live = model
+                .entities
+                .values()
+                .filter(|components| components.contains_key(&Kind::Tracked))
+                .count();
+            prop_assert_eq!(Rc::strong_count(&tracker), live + 1);
+        }
+
+        prop_assert_eq!(Rc::strong_count(&tracker), 1);
+    }
+}
+
+#[derive(Debug, PartialEq)]
+#[repr(align(32))]
+struct Wide(u64);
+
+#[test]
+fn components_are_aligned() {
+    let mut world = World::new();
+
+    let entities = (0..5).map(|_| world.spawn()).collect::<Vec<_>>();
+    for &entity in &entities {
+        world.add(entity, entity as u8);
+        world.add(entity, Wide(entity as u64));
+        world.add(entity, entity as u16);
+    }
+    world.remove::<u16>(entities[1]);
+    world.despawn(entities[2]);
+
+    for &entity in entities.iter().filter(|&&entity| entity != entities[2]) {
+        let wide = world.get::<Wide>(entity).unwrap();
+        assert!((wide as *const Wide).is_aligned());
+        assert_eq!(wide, &Wide(entity as u64));
+        assert_eq!(world.get::<u8>(entity), Some(&(entity as u8)));
+    }
+}
+
+#[test]
+fn compact_frees_empty_storages() {
+    let mut world = World::new();
+
+    let a = world.spawn();
+    let b = world.spawn();
+
+    world.add(a, 1u32);
+    world.add(b, 2u32);
+    world.add(b, 3u8);
+
+    let stats = world.stats();
+    assert_eq!(stats.archetype_count(), 2);
+    assert_eq!(stats.entity_count(), 2);
+    assert!(stats.bytes_used() <= stats.bytes_reserved());
+
+    let reserved = stats.bytes_reserved();
+
+    world.despawn(b);
+    world.compact();
+
+    //shrinking only promises capacity at least the length, not equal to it
+    let stats = world.stats();
+    assert_eq!(stats.archetype_count(), 1);
+    assert_eq!(stats.entity_count(), 1);
+    assert!(stats.bytes_reserved() < reserved);
+    assert!(stats.bytes_reserved() >= stats.bytes_used());
+
+    let generation = world.archetype_generation();
+
+    let c = world.spawn();
+    world.add(c, 4u32);
+    world.add(c, 5u8);
+
+    assert_eq!(world.archetype_generation(), generation);
+    assert_eq!(world.get::<u8>(c), Some(&5));
+    assert_eq!(world.stats().archetype_count(), 2);
+}
+
+#[test]
+fn move_entities_between_worlds() {
+    let tracker = Rc::new(());
+
+    let mut game = World::new();
+    let mut render = World::new();
+
+    let occupied = render.spawn();
+    render.add(occupied, 0u32);
+
+    let a = game.spawn();
+    let b = game.spawn();
+    let c = game.spawn();
+
+    game.add(a, 1u32);
+    game.add(b, 2u32);
+    game.add(
+        b,
+        Tracked {
+            _live: tracker.clone(),
+        },
+    );
+    game.add(c, "three".to_owned());
+
+    let moved = game.move_entities(
+        |world, entity| world.get::<u32>(entity).is_some(),
+        &mut render,
+    );
+
+    assert_eq!(moved.len(), 2);
+    assert!(moved.iter().all(|&(_, new)| new != occupied));
+
+    for (old, new) in moved {
+        assert_eq!(game.get::<u32>(old), None);
+        assert_eq!(render.get::<u32>(new), Some(&(old as u32 + 1)));
+    }
+
+    assert_eq!(game.get::<String>(c).map(String::as_str), Some("three"));
+    assert_eq!(Rc::strong_count(&tracker), 2);
+
+    drop(game);
+    assert_eq!(Rc::strong_count(&tracker), 2);
+
+    drop(render);
+    assert_eq!(Rc::strong_count(&tracker), 1);
+}
+
+#[test]
+fn clone_entity_requires_registered_components() {
+    let mut world = World::new();
+
+    let entity = world.spawn();
+    world.add(entity, 4u8);
+    world.add(entity, "four".to_owned());
+
+    assert_eq!(world.clone_entity(entity), None);
+
+    world.register_clone::<u8>();
+    world.register_clone::<String>();
+
+    let copy = world.clone_entity(entity).unwrap();
+
+    assert_ne!(copy, entity);
+    assert_eq!(world.get::<u8>(copy), Some(&4));
+    assert_eq!(world.get::<String>(copy).map(String::as_str), Some("four"));
+
+    world.despawn(entity);
+    assert_eq!(world.get::<String>(copy).map(String::as_str), Some("four"));
+}
+
+#[test]
+fn query_state_matches_archetypes_created_later() {
+    let mut world = World::new();
+
+    let a = world.spawn();
+    world.add(a, 1u32);
+
+    let mut state = QueryState::<(Entity, &u32)>::new(&world);
+    assert_eq!(state.entities(&world).collect::<Vec<_>>(), [a]);
+    assert_eq!(state.matches().len(), 1);
+
+    let b = world.spawn();
+    world.add(b, 2u32);
+    world.add(b, 3u8);
+
+    let c = world.spawn();
+    world.add(c, 4u8);
+
+    let mut entities = state.entities(&world).collect::<Vec<_>>();
+    entities.sort();
+    assert_eq!(entities, [a, b]);
+
+    //b passed through the u32 archetype on its way to u32 and u8
+    let archetypes = state
+        .matches()
+        .iter()
+        .map(QueryMatch::archetype)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        archetypes,
+        [
+            world.archetype_of(a).unwrap(),
+            world.archetype_of(b).unwrap()
+        ]
+    );
+
+    world.remove::<u32>(b);
+    assert_eq!(state.entities(&world).collect::<Vec<_>>(), [a]);
+    assert_eq!(state.matches().len(), 2);
+}
+
+#[test]
+fn query_state_offsets_locate_components() {
+    let mut world = World::new();
+
+    let entity = world.spawn();
+    world.add(entity, 1u8);
+    world.add(entity, Wide(2));
+    world.add(entity, 3u32);
+
+    let state = QueryState::<(&u32, (&u8, &Wide))>::new(&world);
+    let [query_match] = state.matches() else {
+        panic!("one archetype should match");
+    };
+    assert_eq!(Some(query_match.archetype()), world.archetype_of(entity));
+
+    let addresses = [
+        world.get::<u32>(entity).unwrap() as *const u32 as usize,
+        world.get::<u8>(entity).unwrap() as *const u8 as usize,
+        world.get::<Wide>(entity).unwrap() as *const Wide as usize,
+    ];
+
+    //every component sits at its offset from the start of the row
+    let offsets = query_match.offsets();
+    assert_eq!(offsets.len(), 3);
+    let row = addresses[0] - offsets[0];
+    for (address, offset) in addresses.into_iter().zip(offsets) {
+        assert_eq!(address - row, *offset);
+    }
+}
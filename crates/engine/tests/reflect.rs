@@ -0,0 +1,125 @@
+#![cfg(not(target_arch = "wasm32"))]
+
+use engine::ecs::*;
+use engine::reflect::*;
+use math::prelude::*;
+use std::any;
+
+struct Player {
+    name: String,
+    health: u32,
+    position: Vector<f32, 3>,
+}
+
+engine::reflect!(Player {
+    name,
+    health,
+    position
+});
+
+#[test]
+fn inspect_and_edit() {
+    let mut world = World::new();
+    let mut registry = Registry::new();
+
+    registry.register::<Player>();
+
+    let entity = world.spawn();
+    world.add(entity, 7u8);
+    world.add(
+        entity,
+        Player {
+            name: "boson".to_owned(),
+            health: 4,
+            position: Vector::new([1.0, 2.5, 3.0]),
+        },
+    );
+
+    let player = any::type_name::<Player>();
+
+    let Value::Array(entities) = registry.entities(&world) else {
+        panic!("entities should be an array");
+    };
+    assert_eq!(entities.len(), 1);
+    let entities = entities[0].to_string();
+    assert!(entities.starts_with(r#"{"entity":0,"archetype":1,"components":["#));
+    assert!(entities.contains(&format!("\"{player}\"")));
+
+    let Some(Value::Object(inspection)) = registry.inspect(&world, entity) else {
+        panic!("entity should be inspectable");
+    };
+    let Value::Object(components) = &inspection[2].1 else {
+        panic!("components should be an object");
+    };
+    assert!(components.contains(&("u8".to_owned(), Value::Null)));
+    assert!(components.contains(&(
+        player.to_owned(),
+        Value::Object(vec![
+            ("name".to_owned(), Value::String("boson".to_owned())),
+            ("health".to_owned(), Value::Number(4.0)),
+            (
+                "position".to_owned(),
+                Value::Array(vec![
+                    Value::Number(1.0),
+                    Value::Number(2.5),
+                    Value::Number(3.0)
+                ])
+            ),
+        ])
+    )));
+
+    assert!(registry.set_field(&mut world, entity, player, "health", &Value::Number(9.0)));
+    assert!(!registry.set_field(&mut world, entity, player, "health", &Value::Bool(true)));
+    assert!(!registry.set_field(&mut world, entity, player, "mana", &Value::Number(1.0)));
+    assert!(!registry.set_field(&mut world, entity, "u8", "value", &Value::Number(1.0)));
+
+    for rejected in [-5.0, 9.7, 4294967296.0, f64::INFINITY, f64::NAN] {
+        let value = Value::Number(rejected);
+        assert!(!registry.set_field(&mut world, entity, player, "health", &value));
+    }
+
+    assert_eq!(world.get::<Player>(entity).unwrap().health, 9);
+    assert_eq!(world.get::<Player>(entity).unwrap().name, "boson");
+
+    world.get_mut::<Player>(entity).unwrap().name.push_str("-1");
+    assert_eq!(world.get::<Player>(entity).unwrap().name, "boson-1");
+}
+
+#[test]
+fn numbers_must_fit_their_type() {
+    assert_eq!(
+        u32::from_value(&Value::Number(4294967295.0)),
+        Some(u32::MAX)
+    );
+    assert_eq!(u32::from_value(&Value::Number(-5.0)), None);
+    assert_eq!(u8::from_value(&Value::Number(9.7)), None);
+    assert_eq!(i8::from_value(&Value::Number(-128.0)), Some(i8::MIN));
+    assert_eq!(i8::from_value(&Value::Number(128.0)), None);
+    assert_eq!(
+        u64::from_value(&Value::Number(18446744073709551616.0)),
+        None
+    );
+    assert_eq!(f32::from_value(&Value::Number(1e40)), None);
+    assert_eq!(f32::from_value(&Value::Number(f64::NAN)), None);
+    assert_eq!(f64::from_value(&Value::Number(9.7)), Some(9.7));
+}
+
+#[test]
+fn values_format_as_json() {
+    let value = Value::Object(vec![
+        (
+            "text".to_owned(),
+            Value::String("a \"quoted\"\nline".to_owned()),
+        ),
+        (
+            "list".to_owned(),
+            Value::Array(vec![Value::Bool(true), Value::Null]),
+        ),
+        ("nan".to_owned(), Value::Number(f64::NAN)),
+    ]);
+
+    assert_eq!(
+        value.to_string(),
+        r#"{"text":"a \"quoted\"\nline","list":[true,null],"nan":null}"#
+    );
+}
@@ -0,0 +1,359 @@
+//! Renders with the software and recording backends. Golden images live in
+//! tests/golden; run with `UPDATE_GOLDEN=1` to rewrite them after an
+//! intended change.
+
+#![cfg(not(target_arch = "wasm32"))]
+
+use engine::reflect::ReflectValue;
+use engine::render::recording::{Command, Draw, RecordingRenderer};
+use engine::render::software::Software;
+use engine::render::{Mesh, Render, Renderer, Vertex, VertexFormat, VertexLayout};
+use futures::executor::block_on;
+use math::prelude::*;
+use std::env;
+use std::fs;
+use std::path::Path;
+
+engine::vertex! {
+    #[derive(Clone, Copy)]
+    struct Lit {
+        position: Vector<f32, 3>,
+        normal: Vector<f32, 3>,
+        uv: Vector<f32, 2>,
+        tangent: Vector<f32, 4>,
+        color: LinearRgba,
+    }
+}
+
+engine::vertex! {
+    #[derive(Clone, Copy)]
+    struct Bare {
+        position: Vector<f32, 4>,
+    }
+}
+
+fn lit(x: f32, y: f32, color: LinearRgba) -> Lit {
+    Lit {
+        position: Vector::new([x, y, 0.5]),
+        normal: Vector::new([0.0, 0.0, -1.0]),
+        uv: Vector::new([x * 0.5 + 0.5, y * 0.5 + 0.5]),
+        tangent: Vector::new([1.0, 0.0, 0.0, 1.0]),
+        color,
+    }
+}
+
+fn vertex(x: f32, y: f32, z: f32, color: LinearRgba) -> Vertex {
+    Vertex {
+        position: Vector::new([x, y, z, 1.0]),
+        color,
+    }
+}
+
+//a square over -size..size at depth z
+fn square(size: f32, z: f32, color: LinearRgba) -> [Vertex; 4] {
+    [
+        vertex(-size, -size, z, color),
+        vertex(size, -size, z, color),
+        vertex(size, size, z, color),
+        vertex(-size, size, z, color),
+    ]
+}
+
+const SQUARE: [u16; 6] = [0, 1, 2, 0, 2, 3];
+
+fn rgba8(color: LinearRgba) -> [u8; 4] {
+    Srgba::from(color).to_u8_array()
+}
+
+fn identity() -> Render {
+    Render {
+        mvp: Matrix::identity(),
+    }
+}
+
+fn golden(software: &Software, name: &str) {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/golden")
+        .join(format!("{name}.png"));
+
+    if env::var_os("UPDATE_GOLDEN").is_some() {
+        software.save_png(&path).unwrap();
+        return;
+    }
+
+    let expected = fs::read(&path).expect("missing golden image, run with UPDATE_GOLDEN=1");
+    if software.to_png() != expected {
+        let actual = path.with_extension("actual.png");
+        software.save_png(&actual).unwrap();
+        panic!(
+            "{name} differs from its golden image, see {}",
+            actual.display()
+        );
+    }
+}
+
+#[test]
+fn clears_to_clear_color() {
+    let mut software = block_on(Software::new()).unwrap();
+    software.set_clear_color(LinearRgba::new(0.2, 0.4, 0.6, 1.0));
+
+    software.render(identity(), &[]);
+
+    let expected = rgba8(LinearRgba::new(0.2, 0.4, 0.6, 1.0));
+    assert!(software.pixels().iter().all(|pixel| *pixel == expected));
+    assert_eq!(software.pixels().len(), 256 * 256);
+}
+
+#[test]
+fn shared_edges_leave_no_gaps() {
+    let mut software = Software::with_resolution(37, 23);
+    software.set_clear_color(LinearRgba::BLACK);
+
+    let batch = software.batch(&square(1.0, 0.5, LinearRgba::GREEN), Some(&SQUARE));
+    software.render(identity(), &[&batch]);
+
+    let green = rgba8(LinearRgba::GREEN);
+    assert!(software.pixels().iter().all(|pixel| *pixel == green));
+    assert_eq!(software.depth(18, 11), 0.5);
+}
+
+#[test]
+fn nearest_fragment_wins_in_any_order() {
+    let mut software = Software::with_resolution(16, 16);
+    let near = software.batch(&square(0.5, 0.25, LinearRgba::RED), Some(&SQUARE));
+    let far = software.batch(&square(1.0, 0.75, LinearRgba::BLUE), Some(&SQUARE));
+
+    for batches in [[&near, &far], [&far, &near]] {
+        software.render(identity(), &batches);
+
+        assert_eq!(software.pixel(8, 8).to_u8_array(), rgba8(LinearRgba::RED));
+        assert_eq!(software.pixel(0, 0).to_u8_array(), rgba8(LinearRgba::BLUE));
+    }
+}
+
+#[test]
+fn batches_draw_with_their_own_models() {
+    let mut software = Software::with_resolution(16, 16);
+    software.set_clear_color(LinearRgba::BLACK);
+
+    let vertices = square(0.25, 0.5, LinearRgba::WHITE);
+    let mut left = software.batch(&vertices, Some(&SQUARE));
+    let mut right = software.batch(&vertices[..3], None);
+    left.set_model(Matrix::translation(Vector::new([-0.5, 0.0, 0.0])));
+    right.set_model(Matrix::translation(Vector::new([0.5, 0.0, 0.0])));
+
+    software.render(identity(), &[&left, &right]);
+
+    let (black, white) = (rgba8(LinearRgba::BLACK), rgba8(LinearRgba::WHITE));
+    assert_eq!(software.pixel(4, 6).to_u8_array(), white);
+    assert_eq!(software.pixel(4, 9).to_u8_array(), white);
+    assert_eq!(software.pixel(8, 8).to_u8_array(), black);
+    //the unindexed batch only has the square's lower right triangle
+    assert_eq!(software.pixel(13, 9).to_u8_array(), white);
+    assert_eq!(software.pixel(10, 6).to_u8_array(), black);
+}
+
+#[test]
+fn clips_against_near_and_far_planes() {
+    let mut software = Software::with_resolution(16, 16);
+    software.set_clear_color(LinearRgba::BLACK);
+
+    //the left half is in front of the near plane and the right half past the far one
+    let vertices = [
+        vertex(-1.0, -1.0, -1.0, LinearRgba::WHITE),
+        vertex(1.0, -1.0, 2.0, LinearRgba::WHITE),
+        vertex(1.0, 1.0, 2.0, LinearRgba::WHITE),
+        vertex(-1.0, 1.0, -1.0, LinearRgba::WHITE),
+    ];
+    let batch = software.batch(&vertices, Some(&SQUARE));
+    software.render(identity(), &[&batch]);
+
+    let black = rgba8(LinearRgba::BLACK);
+    assert_eq!(software.pixel(1, 8).to_u8_array(), black);
+    assert_ne!(software.pixel(8, 8).to_u8_array(), black);
+    assert_eq!(software.pixel(14, 8).to_u8_array(), black);
+}
+
+#[test]
+fn perspective_scene_matches_golden() {
+    let mut software = Software::with_resolution(64, 48);
+    software.set_clear_color(LinearRgba::new(0.1, 0.1, 0.1, 1.0));
+
+    //a triangle leaning away from the camera through a square floating in front
+    let triangle = [
+        vertex(-1.5, -1.0, 0.0, LinearRgba::RED),
+        vertex(1.5, -1.0, 0.0, LinearRgba::GREEN),
+        vertex(0.0, 1.2, -3.0, LinearRgba::BLUE),
+    ];
+    let triangle = software.batch(&triangle, None);
+    let square = software.batch(&square(0.4, -0.8, LinearRgba::WHITE), Some(&SQUARE));
+
+    let projection = Matrix::perspective(Radians(1.0), 64.0 / 48.0, 0.1, 10.0);
+    let view = Matrix::look_at(
+        Vector::new([0.0, 0.5, 3.0]),
+        Vector::new([0.0, 0.0, -1.0]),
+        Vector::new([0.0, 1.0, 0.0]),
+    );
+    let render = Render {
+        mvp: projection * view,
+    };
+    software.render(render, &[&triangle, &square]);
+
+    golden(&software, "perspective");
+}
+
+#[test]
+fn declared_layouts_describe_every_field() {
+    let formats = Lit::ATTRIBUTES
+        .iter()
+        .map(|attribute| (attribute.name, attribute.format, attribute.offset))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        formats,
+        [
+            ("position", VertexFormat::Float32x3, 0),
+            ("normal", VertexFormat::Float32x3, 12),
+            ("uv", VertexFormat::Float32x2, 24),
+            ("tangent", VertexFormat::Float32x4, 32),
+            ("color", VertexFormat::Float32x4, 48),
+        ]
+    );
+    assert_eq!(Lit::attribute("uv").unwrap().offset, 24);
+    assert!(Lit::attribute("bitangent").is_none());
+
+    assert_eq!(
+        Vertex::ATTRIBUTES
+            .iter()
+            .map(|attribute| attribute.name)
+            .collect::<Vec<_>>(),
+        ["position", "color"]
+    );
+}
+
+#[test]
+fn shader_locations_follow_attribute_names() {
+    let locations = Lit::layout()
+        .shader_locations()
+        .unwrap()
+        .into_iter()
+        .map(|(attribute, location)| (attribute.name, attribute.offset, location))
+        .collect::<Vec<_>>();
+    assert_eq!(locations, [("position", 0, 0), ("color", 48, 1)]);
+
+    assert_eq!(
+        Vertex::layout().shader_locations().unwrap(),
+        [
+            (Vertex::attribute("position").unwrap(), 0),
+            (Vertex::attribute("color").unwrap(), 1),
+        ]
+    );
+    assert_eq!(Bare::layout().shader_locations(), None);
+}
+
+#[test]
+fn batches_accept_any_vertex_layout() {
+    let mut software = Software::with_resolution(16, 16);
+    let vertices = [
+        lit(-1.0, -1.0, LinearRgba::GREEN),
+        lit(1.0, -1.0, LinearRgba::GREEN),
+        lit(1.0, 1.0, LinearRgba::GREEN),
+        lit(-1.0, 1.0, LinearRgba::GREEN),
+    ];
+    let batch = software.batch(&vertices, Some(&SQUARE));
+    software.render(identity(), &[&batch]);
+
+    //a three component position is given a w of 1
+    assert_eq!(software.pixel(8, 8).to_u8_array(), rgba8(LinearRgba::GREEN));
+    assert!((software.depth(8, 8) - 0.5).abs() < 1e-6);
+
+    let recording = RecordingRenderer::default();
+    recording.batch(&vertices, None);
+    let Command::Batch {
+        vertex_buffer_size,
+        attributes,
+        ..
+    } = &recording.commands()[0]
+    else {
+        panic!("the first command should be a batch");
+    };
+    assert_eq!(*vertex_buffer_size, 4 * 64);
+    assert_eq!(
+        attributes,
+        &["position", "normal", "uv", "tangent", "color"]
+    );
+}
+
+#[test]
+fn records_batches_and_renders() {
+    let mut recording = RecordingRenderer::default();
+
+    let vertices = square(1.0, 0.5, LinearRgba::RED);
+    let square = recording.batch(&vertices, Some(&SQUARE));
+    let triangle = recording.batch(&vertices[..3], None);
+    recording.set_clear_color(LinearRgba::BLACK);
+
+    let mut square = square;
+    let model = Matrix::scale(Vector::new([2.0, 2.0, 1.0]));
+    square.set_model(model);
+
+    let mvp = Matrix::translation(Vector::new([1.0, 2.0, 3.0]));
+    recording.render(Render { mvp }, &[&triangle, &square]);
+
+    assert_eq!((square.id(), triangle.id()), (0, 1));
+    assert_eq!(
+        recording.commands()[1],
+        Command::Batch {
+            id: 1,
+            vertex_count: 3,
+            index_count: None,
+            vertex_buffer_size: 3 * std::mem::size_of::<Vertex>(),
+            index_buffer_size: 0,
+            attributes: vec!["position".to_owned(), "color".to_owned()],
+        }
+    );
+    assert_eq!(
+        recording.renders(),
+        vec![(
+            mvp,
+            vec![
+                Draw {
+                    batch: 1,
+                    vertex_count: 3,
+                    index_count: None,
+                    model: Matrix::identity(),
+                },
+                Draw {
+                    batch: 0,
+                    vertex_count: 4,
+                    index_count: Some(6),
+                    model,
+                },
+            ]
+        )]
+    );
+
+    assert_eq!(recording.take().len(), 4);
+    assert!(recording.commands().is_empty());
+}
+
+#[test]
+fn command_log_round_trips_through_json_values() {
+    let mut recording = RecordingRenderer::default();
+    let batch = recording.batch(&square(1.0, 0.0, LinearRgba::WHITE), Some(&SQUARE));
+    recording.set_clear_color(LinearRgba::BLUE);
+    recording.render(identity(), &[&batch]);
+
+    let engine::reflect::Value::Array(values) = recording.to_value() else {
+        panic!("the log should be an array");
+    };
+    assert!(values[1]
+        .to_string()
+        .starts_with(r#"{"command":"set_clear_color","color":[0,0,1,1]}"#));
+
+    let commands = values
+        .iter()
+        .map(Command::from_value)
+        .collect::<Option<Vec<_>>>()
+        .unwrap();
+    assert_eq!(commands, recording.commands().to_vec());
+}
@@ -0,0 +1,72 @@
+//compare the two backends with
+//cargo bench --bench simd -- --save-baseline scalar
+//cargo bench --bench simd --features simd -- --baseline scalar
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use math::prelude::*;
+use std::hint::black_box;
+
+const COUNT: usize = 1024;
+
+fn transforms() -> Vec<Matrix<f32, 4, 4>> {
+    (0..COUNT)
+        .map(|i| {
+            let i = i as f32;
+            Matrix::translation(Vector::new([i, -i, i * 0.5]))
+                * Matrix::rotation_y(Radians(i * 0.01))
+                * Matrix::scale(Vector::new([1.0 + i * 0.001; 3]))
+        })
+        .collect()
+}
+
+fn vectors() -> Vec<Vector<f32, 4>> {
+    (0..COUNT)
+        .map(|i| {
+            let i = i as f32;
+            Vector::new([i, i * 0.5, -i, 1.0])
+        })
+        .collect()
+}
+
+fn matrix_mul(c: &mut Criterion) {
+    let parents = transforms();
+    let locals = transforms();
+
+    c.bench_function("matrix * matrix", |b| {
+        b.iter(|| {
+            for (parent, local) in parents.iter().zip(&locals) {
+                black_box(black_box(*parent) * black_box(*local));
+            }
+        })
+    });
+}
+
+fn matrix_vector_mul(c: &mut Criterion) {
+    let transforms = transforms();
+    let vectors = vectors();
+
+    c.bench_function("matrix * vector", |b| {
+        b.iter(|| {
+            for (transform, vector) in transforms.iter().zip(&vectors) {
+                black_box(black_box(*transform) * black_box(*vector));
+            }
+        })
+    });
+}
+
+fn vector_arithmetic(c: &mut Criterion) {
+    let vectors = vectors();
+
+    c.bench_function("vector arithmetic", |b| {
+        b.iter(|| {
+            let mut accum = Vector::<f32, 4>::default();
+            for vector in &vectors {
+                accum = (accum + *vector * 0.5 - *vector / 3.0) * *vector;
+            }
+            black_box(accum)
+        })
+    });
+}
+
+criterion_group!(benches, matrix_mul, matrix_vector_mul, vector_arithmetic);
+criterion_main!(benches);
@@ -0,0 +1,300 @@
+//! Angle units and Euler angle orders.
+//!
+//! `Radians` and `Degrees` wrap a bare float so a signature says which one
+//! it wants, and convert into each other with `From`. Trigonometry is only
+//! on `Radians`, the unit the rest of the crate returns. Functions taking an
+//! angle accept `impl Into<Radians<T>>`, so either unit can be passed.
+//!
+//! `EulerRot` names the order three axis rotations are applied in, about
+//! the fixed world axes. `XYZ` rotates about x first, then y, then z, which
+//! is what `Quaternion::from_euler` uses. Angles are always given and
+//! returned as `[x, y, z]` whatever the order, matching an editor's three
+//! fields.
+
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+
+use crate::{matrix::Matrix, quaternion::Quaternion, vector::Vector, Float, Numeric};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+#[repr(transparent)]
+pub struct Radians<T>(pub T);
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+#[repr(transparent)]
+pub struct Degrees<T>(pub T);
+
+macro_rules! angle {
+    ($($type:ident => $half_turn:expr),*) => {
+        $(
+            impl<T> $type<T>
+            where
+                T: Float,
+            {
+                pub fn half_turn() -> Self {
+                    Self($half_turn)
+                }
+
+                //the same angle in -half_turn..=half_turn
+                pub fn wrap(self) -> Self {
+                    let half_turn = $half_turn;
+                    let turn = half_turn + half_turn;
+                    let wrapped = self.0 - turn * ((self.0 + half_turn) / turn).floor();
+                    //the floor above maps exactly -half_turn to itself
+                    if wrapped == -half_turn {
+                        Self(half_turn)
+                    } else {
+                        Self(wrapped)
+                    }
+                }
+            }
+
+            impl<T> Add for $type<T>
+            where
+                T: Numeric,
+            {
+                type Output = Self;
+
+                fn add(self, rhs: Self) -> Self {
+                    Self(self.0 + rhs.0)
+                }
+            }
+
+            impl<T> AddAssign for $type<T>
+            where
+                T: Numeric,
+            {
+                fn add_assign(&mut self, rhs: Self) {
+                    self.0 += rhs.0;
+                }
+            }
+
+            impl<T> Sub for $type<T>
+            where
+                T: Numeric,
+            {
+                type Output = Self;
+
+                fn sub(self, rhs: Self) -> Self {
+                    Self(self.0 - rhs.0)
+                }
+            }
+
+            impl<T> SubAssign for $type<T>
+            where
+                T: Numeric,
+            {
+                fn sub_assign(&mut self, rhs: Self) {
+                    self.0 -= rhs.0;
+                }
+            }
+
+            impl<T> Mul<T> for $type<T>
+            where
+                T: Numeric,
+            {
+                type Output = Self;
+
+                fn mul(self, rhs: T) -> Self {
+                    Self(self.0 * rhs)
+                }
+            }
+
+            impl<T> Div<T> for $type<T>
+            where
+                T: Numeric,
+            {
+                type Output = Self;
+
+                fn div(self, rhs: T) -> Self {
+                    Self(self.0 / rhs)
+                }
+            }
+
+            //the ratio of two angles has no unit
+            impl<T> Div for $type<T>
+            where
+                T: Numeric,
+            {
+                type Output = T;
+
+                fn div(self, rhs: Self) -> T {
+                    self.0 / rhs.0
+                }
+            }
+
+            impl<T> Neg for $type<T>
+            where
+                T: Numeric + Neg<Output = T>,
+            {
+                type Output = Self;
+
+                fn neg(self) -> Self {
+                    Self(-self.0)
+                }
+            }
+        )*
+    };
+}
+
+angle!(Radians => T::PI, Degrees => T::from_f64(180.0));
+
+impl<T> Radians<T>
+where
+    T: Float,
+{
+    pub fn to_degrees(self) -> Degrees<T> {
+        Degrees(self.0 * (T::from_f64(180.0) / T::PI))
+    }
+
+    pub fn sin(self) -> T {
+        self.0.sin()
+    }
+
+    pub fn cos(self) -> T {
+        self.0.cos()
+    }
+
+    pub fn tan(self) -> T {
+        self.0.tan()
+    }
+
+    pub fn sin_cos(self) -> (T, T) {
+        self.0.sin_cos()
+    }
+}
+
+impl<T> Degrees<T>
+where
+    T: Float,
+{
+    pub fn to_radians(self) -> Radians<T> {
+        Radians(self.0 * (T::PI / T::from_f64(180.0)))
+    }
+}
+
+impl<T> From<Degrees<T>> for Radians<T>
+where
+    T: Float,
+{
+    fn from(degrees: Degrees<T>) -> Self {
+        degrees.to_radians()
+    }
+}
+
+impl<T> From<Radians<T>> for Degrees<T>
+where
+    T: Float,
+{
+    fn from(radians: Radians<T>) -> Self {
+        radians.to_degrees()
+    }
+}
+
+//the order rotations about the fixed world axes are applied in, so XYZ is
+//z * y * x as a matrix product. an order is the reverse of the same
+//rotation described about the body's own, moving axes
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum EulerRot {
+    #[default]
+    XYZ,
+    XZY,
+    YXZ,
+    YZX,
+    ZXY,
+    ZYX,
+}
+
+impl EulerRot {
+    pub const ALL: [Self; 6] = [
+        Self::XYZ,
+        Self::XZY,
+        Self::YXZ,
+        Self::YZX,
+        Self::ZXY,
+        Self::ZYX,
+    ];
+
+    //the axes in the order they are applied
+    pub fn axes(self) -> [usize; 3] {
+        match self {
+            Self::XYZ => [0, 1, 2],
+            Self::XZY => [0, 2, 1],
+            Self::YXZ => [1, 0, 2],
+            Self::YZX => [1, 2, 0],
+            Self::ZXY => [2, 0, 1],
+            Self::ZYX => [2, 1, 0],
+        }
+    }
+
+    //whether the axes are a cyclic shift of xyz. the others mirror the
+    //matrix entries, flipping the sign of every extracted angle
+    fn even(self) -> bool {
+        let [first, second, _] = self.axes();
+        (first + 1) % 3 == second
+    }
+}
+
+fn axis<T>(index: usize) -> Vector<T, 3>
+where
+    T: Numeric,
+{
+    let mut axis = Vector::default();
+    axis[index] = T::one();
+    axis
+}
+
+impl<T> Quaternion<T>
+where
+    T: Float,
+{
+    //angles are [x, y, z], applied in the given order
+    pub fn from_euler_rot(order: EulerRot, angles: [Radians<T>; 3]) -> Self {
+        let mut rotation = Self::identity();
+        for index in order.axes() {
+            rotation = Self::from_axis_angle(axis(index), angles[index]) * rotation;
+        }
+        rotation
+    }
+
+    //see Matrix::to_euler_rot
+    pub fn to_euler_rot(self, order: EulerRot) -> [Radians<T>; 3] {
+        self.to_matrix3().to_euler_rot(order)
+    }
+}
+
+impl<T> Matrix<T, 3, 3>
+where
+    T: Float,
+{
+    pub fn from_euler_rot(order: EulerRot, angles: [Radians<T>; 3]) -> Self {
+        Quaternion::from_euler_rot(order, angles).to_matrix3()
+    }
+
+    //the [x, y, z] angles that rebuild this rotation in the given order. the
+    //middle axis is kept in -90..=90 degrees and the others in -180..=180.
+    //in gimbal lock, where the middle axis is at ±90, the first angle is 0
+    pub fn to_euler_rot(self, order: EulerRot) -> [Radians<T>; 3] {
+        let [i, j, k] = order.axes();
+        let m = self;
+        let sign = if order.even() { T::one() } else { -T::one() };
+
+        //atan2 rather than asin, which loses half its precision near ±90
+        let cos = (m[k][j] * m[k][j] + m[k][k] * m[k][k]).sqrt();
+        let middle = (-sign * m[k][i]).atan2(cos);
+
+        let (first, last) = if cos > T::EPSILON * T::from_f64(16.0) {
+            (
+                (sign * m[k][j]).atan2(m[k][k]),
+                (sign * m[j][i]).atan2(m[i][i]),
+            )
+        } else {
+            (T::zero(), (-sign * m[i][j]).atan2(m[j][j]))
+        };
+
+        let mut angles = [Radians(T::zero()); 3];
+        angles[i] = Radians(first);
+        angles[j] = Radians(middle);
+        angles[k] = Radians(last);
+        angles
+    }
+}
@@ -0,0 +1,364 @@
+//! `LinearRgba` is the working space: mixing, blending and anything handed
+//! to a shader as a float happens in linear light. `Srgba` is the gamma
+//! encoded space colors are authored and stored in, and the only one that
+//! packs to bytes. `Hsla` is an sRGB-encoded color picker space and `Oklab`
+//! is a perceptual space for gradients that keep an even brightness.
+//!
+//! Alpha is straight, not premultiplied, and is never gamma encoded.
+
+//the oklab constants are kept as published
+#![allow(clippy::excessive_precision)]
+
+use crate::{matrix::Matrix, vector::Vector};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[repr(C)]
+pub struct LinearRgba {
+    pub red: f32,
+    pub green: f32,
+    pub blue: f32,
+    pub alpha: f32,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[repr(C)]
+pub struct Srgba {
+    pub red: f32,
+    pub green: f32,
+    pub blue: f32,
+    pub alpha: f32,
+}
+
+//hue is in degrees, the rest are in 0..1
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Hsla {
+    pub hue: f32,
+    pub saturation: f32,
+    pub lightness: f32,
+    pub alpha: f32,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Oklab {
+    pub lightness: f32,
+    pub a: f32,
+    pub b: f32,
+    pub alpha: f32,
+}
+
+macro_rules! rgba {
+    ($($type:ident),*) => {
+        $(
+            impl $type {
+                pub const BLACK: Self = Self::rgb(0.0, 0.0, 0.0);
+                pub const WHITE: Self = Self::rgb(1.0, 1.0, 1.0);
+                pub const RED: Self = Self::rgb(1.0, 0.0, 0.0);
+                pub const GREEN: Self = Self::rgb(0.0, 1.0, 0.0);
+                pub const BLUE: Self = Self::rgb(0.0, 0.0, 1.0);
+                pub const TRANSPARENT: Self = Self::new(0.0, 0.0, 0.0, 0.0);
+
+                pub const fn new(red: f32, green: f32, blue: f32, alpha: f32) -> Self {
+                    Self {
+                        red,
+                        green,
+                        blue,
+                        alpha,
+                    }
+                }
+
+                pub const fn rgb(red: f32, green: f32, blue: f32) -> Self {
+                    Self::new(red, green, blue, 1.0)
+                }
+
+                pub fn to_array(self) -> [f32; 4] {
+                    [self.red, self.green, self.blue, self.alpha]
+                }
+            }
+
+            impl Default for $type {
+                fn default() -> Self {
+                    Self::WHITE
+                }
+            }
+
+            impl From<[f32; 4]> for $type {
+                fn from([red, green, blue, alpha]: [f32; 4]) -> Self {
+                    Self::new(red, green, blue, alpha)
+                }
+            }
+
+            impl From<$type> for [f32; 4] {
+                fn from(color: $type) -> Self {
+                    color.to_array()
+                }
+            }
+
+            impl From<Vector<f32, 4>> for $type {
+                fn from(vector: Vector<f32, 4>) -> Self {
+                    Self::from(*vector)
+                }
+            }
+
+            impl From<$type> for Vector<f32, 4> {
+                fn from(color: $type) -> Self {
+                    Vector::new(color.to_array())
+                }
+            }
+        )*
+    };
+}
+
+rgba!(LinearRgba, Srgba);
+
+fn encode(linear: f32) -> f32 {
+    let magnitude = linear.abs();
+    let encoded = if magnitude <= 0.0031308 {
+        magnitude * 12.92
+    } else {
+        1.055 * magnitude.powf(1.0 / 2.4) - 0.055
+    };
+    encoded.copysign(linear)
+}
+
+fn decode(encoded: f32) -> f32 {
+    let magnitude = encoded.abs();
+    let linear = if magnitude <= 0.04045 {
+        magnitude / 12.92
+    } else {
+        ((magnitude + 0.055) / 1.055).powf(2.4)
+    };
+    linear.copysign(encoded)
+}
+
+impl LinearRgba {
+    pub fn mix(self, other: Self, t: f32) -> Self {
+        Self::from(Vector::from(self).lerp(Vector::from(other), t))
+    }
+
+    //composites self over background
+    pub fn blend(self, background: Self) -> Self {
+        let alpha = self.alpha + background.alpha * (1.0 - self.alpha);
+        if alpha == 0.0 {
+            return Self::TRANSPARENT;
+        }
+
+        let channel = |source: f32, destination: f32| {
+            (source * self.alpha + destination * background.alpha * (1.0 - self.alpha)) / alpha
+        };
+
+        Self::new(
+            channel(self.red, background.red),
+            channel(self.green, background.green),
+            channel(self.blue, background.blue),
+            alpha,
+        )
+    }
+}
+
+impl Srgba {
+    //channels are clamped to 0..1 and rounded to the nearest step
+    pub fn to_u8_array(self) -> [u8; 4] {
+        self.to_array()
+            .map(|channel| (channel.clamp(0.0, 1.0) * 255.0).round() as u8)
+    }
+
+    pub fn from_u8_array(bytes: [u8; 4]) -> Self {
+        Self::from(bytes.map(|byte| byte as f32 / 255.0))
+    }
+
+    //packed as 0xRRGGBBAA
+    pub fn to_u32(self) -> u32 {
+        u32::from_be_bytes(self.to_u8_array())
+    }
+
+    pub fn from_u32(packed: u32) -> Self {
+        Self::from_u8_array(packed.to_be_bytes())
+    }
+}
+
+impl Hsla {
+    pub fn new(hue: f32, saturation: f32, lightness: f32, alpha: f32) -> Self {
+        Self {
+            hue,
+            saturation,
+            lightness,
+            alpha,
+        }
+    }
+
+    //interpolates hue the short way around the wheel
+    pub fn mix(self, other: Self, t: f32) -> Self {
+        let mut delta = (other.hue - self.hue).rem_euclid(360.0);
+        if delta > 180.0 {
+            delta -= 360.0;
+        }
+
+        Self::new(
+            (self.hue + delta * t).rem_euclid(360.0),
+            self.saturation + (other.saturation - self.saturation) * t,
+            self.lightness + (other.lightness - self.lightness) * t,
+            self.alpha + (other.alpha - self.alpha) * t,
+        )
+    }
+}
+
+impl Oklab {
+    pub fn new(lightness: f32, a: f32, b: f32, alpha: f32) -> Self {
+        Self {
+            lightness,
+            a,
+            b,
+            alpha,
+        }
+    }
+
+    pub fn mix(self, other: Self, t: f32) -> Self {
+        Self::new(
+            self.lightness + (other.lightness - self.lightness) * t,
+            self.a + (other.a - self.a) * t,
+            self.b + (other.b - self.b) * t,
+            self.alpha + (other.alpha - self.alpha) * t,
+        )
+    }
+}
+
+impl From<Srgba> for LinearRgba {
+    fn from(color: Srgba) -> Self {
+        Self::new(
+            decode(color.red),
+            decode(color.green),
+            decode(color.blue),
+            color.alpha,
+        )
+    }
+}
+
+impl From<LinearRgba> for Srgba {
+    fn from(color: LinearRgba) -> Self {
+        Self::new(
+            encode(color.red),
+            encode(color.green),
+            encode(color.blue),
+            color.alpha,
+        )
+    }
+}
+
+impl From<Srgba> for Hsla {
+    fn from(color: Srgba) -> Self {
+        let Srgba {
+            red,
+            green,
+            blue,
+            alpha,
+        } = color;
+
+        let max = red.max(green).max(blue);
+        let min = red.min(green).min(blue);
+        let lightness = (max + min) * 0.5;
+        let chroma = max - min;
+
+        if chroma == 0.0 {
+            return Self::new(0.0, 0.0, lightness, alpha);
+        }
+
+        let saturation = chroma / (1.0 - (2.0 * lightness - 1.0).abs());
+        let hue = if max == red {
+            (green - blue) / chroma
+        } else if max == green {
+            (blue - red) / chroma + 2.0
+        } else {
+            (red - green) / chroma + 4.0
+        };
+
+        Self::new((hue * 60.0).rem_euclid(360.0), saturation, lightness, alpha)
+    }
+}
+
+impl From<Hsla> for Srgba {
+    fn from(color: Hsla) -> Self {
+        let Hsla {
+            hue,
+            saturation,
+            lightness,
+            alpha,
+        } = color;
+
+        let a = saturation * lightness.min(1.0 - lightness);
+        let channel = |n: f32| {
+            let k = (n + hue / 30.0).rem_euclid(12.0);
+            lightness - a * (k - 3.0).min(9.0 - k).clamp(-1.0, 1.0)
+        };
+
+        Self::new(channel(0.0), channel(8.0), channel(4.0), alpha)
+    }
+}
+
+//from https://bottosson.github.io/posts/oklab/
+const LINEAR_TO_LMS: [[f32; 3]; 3] = [
+    [0.4122214708, 0.5363325363, 0.0514459929],
+    [0.2119034982, 0.6806995451, 0.1073969566],
+    [0.0883024619, 0.2817188376, 0.6299787005],
+];
+
+const LMS_TO_OKLAB: [[f32; 3]; 3] = [
+    [0.2104542553, 0.7936177850, -0.0040720468],
+    [1.9779984951, -2.4285922050, 0.4505937099],
+    [0.0259040371, 0.7827717662, -0.8086757660],
+];
+
+const OKLAB_TO_LMS: [[f32; 3]; 3] = [
+    [1.0, 0.3963377774, 0.2158037573],
+    [1.0, -0.1055613458, -0.0638541728],
+    [1.0, -0.0894841775, -1.2914855480],
+];
+
+const LMS_TO_LINEAR: [[f32; 3]; 3] = [
+    [4.0767416621, -3.3077115913, 0.2309699292],
+    [-1.2684380046, 2.6097574011, -0.3413193965],
+    [-0.0041960863, -0.7034186147, 1.7076147010],
+];
+
+fn matrix(data: [[f32; 3]; 3]) -> Matrix<f32, 3, 3> {
+    Matrix::new(data.map(Vector::new))
+}
+
+impl From<LinearRgba> for Oklab {
+    fn from(color: LinearRgba) -> Self {
+        let rgb = Vector::new([color.red, color.green, color.blue]);
+        let lms = matrix(LINEAR_TO_LMS) * rgb;
+        let lab = matrix(LMS_TO_OKLAB) * Vector::new((*lms).map(f32::cbrt));
+        Self::new(lab[0], lab[1], lab[2], color.alpha)
+    }
+}
+
+impl From<Oklab> for LinearRgba {
+    fn from(color: Oklab) -> Self {
+        let lab = Vector::new([color.lightness, color.a, color.b]);
+        let lms = matrix(OKLAB_TO_LMS) * lab;
+        let rgb = matrix(LMS_TO_LINEAR) * Vector::new((*lms).map(|x| x * x * x));
+        Self::new(rgb[0], rgb[1], rgb[2], color.alpha)
+    }
+}
+
+//the remaining pairs go through the conversions above
+macro_rules! convert_via {
+    ($($from:ident => $to:ident via $via:ident),*) => {
+        $(
+            impl From<$from> for $to {
+                fn from(color: $from) -> Self {
+                    Self::from($via::from(color))
+                }
+            }
+        )*
+    };
+}
+
+convert_via!(
+    Hsla => LinearRgba via Srgba,
+    LinearRgba => Hsla via Srgba,
+    Oklab => Srgba via LinearRgba,
+    Srgba => Oklab via LinearRgba,
+    Oklab => Hsla via Srgba,
+    Hsla => Oklab via LinearRgba
+);
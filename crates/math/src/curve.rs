@@ -0,0 +1,435 @@
+use crate::{vector::Vector, Float, Numeric};
+
+//parameterized over t in 0..1, with derivatives taken with respect to t
+pub trait Curve<T, const N: usize>
+where
+    T: Float,
+{
+    fn evaluate(&self, t: T) -> Vector<T, N>;
+    fn derivative(&self, t: T) -> Vector<T, N>;
+    fn second_derivative(&self, t: T) -> Vector<T, N>;
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct QuadraticBezier<T, const N: usize>
+where
+    T: Numeric,
+{
+    pub points: [Vector<T, N>; 3],
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CubicBezier<T, const N: usize>
+where
+    T: Numeric,
+{
+    pub points: [Vector<T, N>; 4],
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Hermite<T, const N: usize>
+where
+    T: Numeric,
+{
+    pub start: Vector<T, N>,
+    pub start_tangent: Vector<T, N>,
+    pub end: Vector<T, N>,
+    pub end_tangent: Vector<T, N>,
+}
+
+//passes through every point but the first and last, which only shape the ends
+#[derive(Clone, Debug, PartialEq)]
+pub struct CatmullRom<T, const N: usize>
+where
+    T: Numeric,
+{
+    points: Vec<Vector<T, N>>,
+}
+
+//uniform cubic, approximating rather than passing through its points
+#[derive(Clone, Debug, PartialEq)]
+pub struct BSpline<T, const N: usize>
+where
+    T: Numeric,
+{
+    points: Vec<Vector<T, N>>,
+}
+
+impl<T, const N: usize> QuadraticBezier<T, N>
+where
+    T: Numeric,
+{
+    pub fn new(points: [Vector<T, N>; 3]) -> Self {
+        Self { points }
+    }
+}
+
+impl<T, const N: usize> CubicBezier<T, N>
+where
+    T: Numeric,
+{
+    pub fn new(points: [Vector<T, N>; 4]) -> Self {
+        Self { points }
+    }
+}
+
+impl<T, const N: usize> Hermite<T, N>
+where
+    T: Numeric,
+{
+    pub fn new(
+        start: Vector<T, N>,
+        start_tangent: Vector<T, N>,
+        end: Vector<T, N>,
+        end_tangent: Vector<T, N>,
+    ) -> Self {
+        Self {
+            start,
+            start_tangent,
+            end,
+            end_tangent,
+        }
+    }
+}
+
+impl<T, const N: usize> CatmullRom<T, N>
+where
+    T: Numeric,
+{
+    //returns none with fewer than four points
+    pub fn new(points: Vec<Vector<T, N>>) -> Option<Self> {
+        if points.len() < 4 {
+            None?
+        }
+        Some(Self { points })
+    }
+
+    pub fn points(&self) -> &[Vector<T, N>] {
+        &self.points
+    }
+}
+
+impl<T, const N: usize> BSpline<T, N>
+where
+    T: Numeric,
+{
+    //returns none with fewer than four points
+    pub fn new(points: Vec<Vector<T, N>>) -> Option<Self> {
+        if points.len() < 4 {
+            None?
+        }
+        Some(Self { points })
+    }
+
+    pub fn points(&self) -> &[Vector<T, N>] {
+        &self.points
+    }
+}
+
+fn weigh<T, const N: usize, const M: usize>(
+    points: [Vector<T, N>; M],
+    weights: [T; M],
+) -> Vector<T, N>
+where
+    T: Numeric,
+{
+    let mut sum = Vector::default();
+    for (point, weight) in points.into_iter().zip(weights) {
+        sum += point * weight;
+    }
+    sum
+}
+
+fn constant<T: Float>(value: f64) -> T {
+    T::from_f64(value)
+}
+
+//splits t over segments, returning the segment and the t within it
+fn segment<T: Float>(t: T, segments: usize) -> (usize, T) {
+    let scaled = t.clamp(T::zero(), T::one()) * constant(segments as f64);
+    let index = (scaled.floor().to_f64() as usize).min(segments - 1);
+    (index, scaled - constant(index as f64))
+}
+
+impl<T, const N: usize> Curve<T, N> for QuadraticBezier<T, N>
+where
+    T: Float,
+{
+    fn evaluate(&self, t: T) -> Vector<T, N> {
+        let s = T::one() - t;
+        weigh(self.points, [s * s, constant::<T>(2.0) * s * t, t * t])
+    }
+
+    fn derivative(&self, t: T) -> Vector<T, N> {
+        let two = constant::<T>(2.0);
+        let s = T::one() - t;
+        weigh(self.points, [-two * s, two * (s - t), two * t])
+    }
+
+    fn second_derivative(&self, _: T) -> Vector<T, N> {
+        weigh(self.points, [constant(2.0), constant(-4.0), constant(2.0)])
+    }
+}
+
+impl<T, const N: usize> Curve<T, N> for CubicBezier<T, N>
+where
+    T: Float,
+{
+    fn evaluate(&self, t: T) -> Vector<T, N> {
+        let three = constant::<T>(3.0);
+        let s = T::one() - t;
+        weigh(
+            self.points,
+            [s * s * s, three * s * s * t, three * s * t * t, t * t * t],
+        )
+    }
+
+    fn derivative(&self, t: T) -> Vector<T, N> {
+        let (three, six) = (constant::<T>(3.0), constant::<T>(6.0));
+        let s = T::one() - t;
+        weigh(
+            self.points,
+            [
+                -three * s * s,
+                three * s * s - six * s * t,
+                six * s * t - three * t * t,
+                three * t * t,
+            ],
+        )
+    }
+
+    fn second_derivative(&self, t: T) -> Vector<T, N> {
+        let (six, twelve) = (constant::<T>(6.0), constant::<T>(12.0));
+        let s = T::one() - t;
+        weigh(
+            self.points,
+            [six * s, six * t - twelve * s, six * s - twelve * t, six * t],
+        )
+    }
+}
+
+impl<T, const N: usize> Hermite<T, N>
+where
+    T: Numeric,
+{
+    fn points(&self) -> [Vector<T, N>; 4] {
+        [self.start, self.start_tangent, self.end, self.end_tangent]
+    }
+}
+
+impl<T, const N: usize> Curve<T, N> for Hermite<T, N>
+where
+    T: Float,
+{
+    fn evaluate(&self, t: T) -> Vector<T, N> {
+        let c = constant::<T>;
+        let (t2, t3) = (t * t, t * t * t);
+        weigh(
+            self.points(),
+            [
+                c(2.0) * t3 - c(3.0) * t2 + T::one(),
+                t3 - c(2.0) * t2 + t,
+                c(3.0) * t2 - c(2.0) * t3,
+                t3 - t2,
+            ],
+        )
+    }
+
+    fn derivative(&self, t: T) -> Vector<T, N> {
+        let c = constant::<T>;
+        let t2 = t * t;
+        weigh(
+            self.points(),
+            [
+                c(6.0) * t2 - c(6.0) * t,
+                c(3.0) * t2 - c(4.0) * t + T::one(),
+                c(6.0) * t - c(6.0) * t2,
+                c(3.0) * t2 - c(2.0) * t,
+            ],
+        )
+    }
+
+    fn second_derivative(&self, t: T) -> Vector<T, N> {
+        let c = constant::<T>;
+        weigh(
+            self.points(),
+            [
+                c(12.0) * t - c(6.0),
+                c(6.0) * t - c(4.0),
+                c(6.0) - c(12.0) * t,
+                c(6.0) * t - c(2.0),
+            ],
+        )
+    }
+}
+
+//the four points around a segment and the rate t advances through it
+fn window<T, const N: usize>(points: &[Vector<T, N>], t: T) -> ([Vector<T, N>; 4], T, T)
+where
+    T: Float,
+{
+    let segments = points.len() - 3;
+    let (index, local) = segment(t, segments);
+    let window = [
+        points[index],
+        points[index + 1],
+        points[index + 2],
+        points[index + 3],
+    ];
+    (window, local, constant(segments as f64))
+}
+
+impl<T, const N: usize> Curve<T, N> for CatmullRom<T, N>
+where
+    T: Float,
+{
+    fn evaluate(&self, t: T) -> Vector<T, N> {
+        let c = constant::<T>;
+        let (points, t, _) = window(&self.points, t);
+        let (t2, t3) = (t * t, t * t * t);
+        let weights = [
+            c(2.0) * t2 - t - t3,
+            c(3.0) * t3 - c(5.0) * t2 + c(2.0),
+            c(4.0) * t2 + t - c(3.0) * t3,
+            t3 - t2,
+        ];
+        weigh(points, weights) * c(0.5)
+    }
+
+    fn derivative(&self, t: T) -> Vector<T, N> {
+        let c = constant::<T>;
+        let (points, t, rate) = window(&self.points, t);
+        let t2 = t * t;
+        let weights = [
+            c(4.0) * t - c(3.0) * t2 - T::one(),
+            c(9.0) * t2 - c(10.0) * t,
+            c(8.0) * t - c(9.0) * t2 + T::one(),
+            c(3.0) * t2 - c(2.0) * t,
+        ];
+        weigh(points, weights) * (c(0.5) * rate)
+    }
+
+    fn second_derivative(&self, t: T) -> Vector<T, N> {
+        let c = constant::<T>;
+        let (points, t, rate) = window(&self.points, t);
+        let weights = [
+            c(4.0) - c(6.0) * t,
+            c(18.0) * t - c(10.0),
+            c(8.0) - c(18.0) * t,
+            c(6.0) * t - c(2.0),
+        ];
+        weigh(points, weights) * (c(0.5) * rate * rate)
+    }
+}
+
+impl<T, const N: usize> Curve<T, N> for BSpline<T, N>
+where
+    T: Float,
+{
+    fn evaluate(&self, t: T) -> Vector<T, N> {
+        let c = constant::<T>;
+        let (points, t, _) = window(&self.points, t);
+        let s = T::one() - t;
+        let (t2, t3) = (t * t, t * t * t);
+        let weights = [
+            s * s * s,
+            c(3.0) * t3 - c(6.0) * t2 + c(4.0),
+            c(3.0) * (t2 + t - t3) + T::one(),
+            t3,
+        ];
+        weigh(points, weights) / c(6.0)
+    }
+
+    fn derivative(&self, t: T) -> Vector<T, N> {
+        let c = constant::<T>;
+        let (points, t, rate) = window(&self.points, t);
+        let s = T::one() - t;
+        let t2 = t * t;
+        let weights = [
+            c(-3.0) * s * s,
+            c(9.0) * t2 - c(12.0) * t,
+            c(6.0) * t - c(9.0) * t2 + c(3.0),
+            c(3.0) * t2,
+        ];
+        weigh(points, weights) * (rate / c(6.0))
+    }
+
+    fn second_derivative(&self, t: T) -> Vector<T, N> {
+        let c = constant::<T>;
+        let (points, t, rate) = window(&self.points, t);
+        let s = T::one() - t;
+        let weights = [
+            c(6.0) * s,
+            c(18.0) * t - c(12.0),
+            c(6.0) - c(18.0) * t,
+            c(6.0) * t,
+        ];
+        weigh(points, weights) * (rate * rate / c(6.0))
+    }
+}
+
+//a table of distances along a curve, mapping distance back to t
+//so that objects can move along it at constant speed
+#[derive(Clone, Debug, PartialEq)]
+pub struct ArcLength<T>
+where
+    T: Numeric,
+{
+    //the distance travelled at t = i / (len - 1)
+    distances: Vec<T>,
+}
+
+impl<T> ArcLength<T>
+where
+    T: Float,
+{
+    //approximates the curve with samples straight segments
+    pub fn new<C, const N: usize>(curve: &C, samples: usize) -> Self
+    where
+        C: Curve<T, N>,
+    {
+        let samples = samples.max(1);
+        let mut distances = Vec::with_capacity(samples + 1);
+        let mut distance = T::zero();
+        let mut previous = curve.evaluate(T::zero());
+
+        distances.push(distance);
+        for i in 1..=samples {
+            let point = curve.evaluate(constant::<T>(i as f64) / constant(samples as f64));
+            distance += point.distance(&previous);
+            distances.push(distance);
+            previous = point;
+        }
+
+        Self { distances }
+    }
+
+    pub fn length(&self) -> T {
+        self.distances[self.distances.len() - 1]
+    }
+
+    //the t at which the curve has travelled distance, clamped to the curve
+    pub fn parameter(&self, distance: T) -> T {
+        let segments = self.distances.len() - 1;
+        let distance = distance.clamp(T::zero(), self.length());
+
+        let index = self
+            .distances
+            .partition_point(|sample| *sample < distance)
+            .clamp(1, segments);
+
+        let (start, end) = (self.distances[index - 1], self.distances[index]);
+        let local = if end > start {
+            (distance - start) / (end - start)
+        } else {
+            T::zero()
+        };
+
+        (constant::<T>((index - 1) as f64) + local) / constant(segments as f64)
+    }
+
+    //the t at which the curve has travelled fraction of its length
+    pub fn parameter_at_fraction(&self, fraction: T) -> T {
+        self.parameter(fraction * self.length())
+    }
+}
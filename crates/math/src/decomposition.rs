@@ -0,0 +1,432 @@
+#![allow(clippy::needless_range_loop)]
+
+use std::cmp::Ordering;
+
+use crate::{fixed::Fixed, matrix::Matrix, vector::Vector, Float, Numeric};
+
+pub(crate) fn abs<T>(value: T) -> T
+where
+    T: Numeric,
+{
+    if value < T::zero() {
+        T::zero() - value
+    } else {
+        value
+    }
+}
+
+//the relative rounding error of a type, zero for exact types like integers
+pub(crate) trait Epsilon: Numeric {
+    fn epsilon() -> Self;
+}
+
+impl<T> Epsilon for T
+where
+    T: Numeric,
+{
+    default fn epsilon() -> Self {
+        T::zero()
+    }
+}
+
+macro_rules! epsilon {
+    ($($type:ty),*) => {
+        $(
+            impl Epsilon for $type {
+                fn epsilon() -> Self {
+                    <$type>::EPSILON
+                }
+            }
+        )*
+    };
+}
+
+epsilon!(f32, f64);
+
+impl<const FRAC: u32> Epsilon for Fixed<i32, FRAC> {
+    fn epsilon() -> Self {
+        Self::EPSILON
+    }
+}
+
+impl<const FRAC: u32> Epsilon for Fixed<i64, FRAC> {
+    fn epsilon() -> Self {
+        Self::EPSILON
+    }
+}
+
+//row-pivoted lu decomposition, where the rows of the decomposed matrix
+//taken in permutation order equal lower * upper
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Lu<T, const N: usize>
+where
+    T: Numeric,
+{
+    //lower below the diagonal with an implied unit diagonal, upper on and above it
+    lu: Matrix<T, N, N>,
+    permutation: [usize; N],
+    even: bool,
+}
+
+impl<T, const N: usize> Lu<T, N>
+where
+    T: Numeric,
+{
+    //returns none if the matrix is singular, or a pivot is within rounding
+    //error of zero relative to the largest one
+    pub fn new(matrix: Matrix<T, N, N>) -> Option<Self> {
+        let zero = T::default();
+
+        let mut lu = matrix;
+        let mut permutation = [0; N];
+        let mut even = true;
+
+        for i in 0..N {
+            permutation[i] = i;
+        }
+
+        for k in 0..N {
+            let mut pivot = k;
+            for row in k + 1..N {
+                if abs(lu[row][k]) > abs(lu[pivot][k]) {
+                    pivot = row;
+                }
+            }
+
+            if lu[pivot][k] == zero {
+                None?
+            }
+
+            if pivot != k {
+                lu.swap(pivot, k);
+                permutation.swap(pivot, k);
+                even = !even;
+            }
+
+            for row in k + 1..N {
+                let factor = lu[row][k] / lu[k][k];
+                lu[row][k] = factor;
+                for col in k + 1..N {
+                    let value = lu[k][col];
+                    lu[row][col] -= factor * value;
+                }
+            }
+        }
+
+        let mut largest = zero;
+        for i in 0..N {
+            if abs(lu[i][i]) > largest {
+                largest = abs(lu[i][i]);
+            }
+        }
+
+        let mut tolerance = zero;
+        for _ in 0..N {
+            tolerance += largest * T::epsilon();
+        }
+
+        for i in 0..N {
+            if abs(lu[i][i]) <= tolerance {
+                None?
+            }
+        }
+
+        Some(Self {
+            lu,
+            permutation,
+            even,
+        })
+    }
+
+    pub fn lower(&self) -> Matrix<T, N, N> {
+        let mut lower = Matrix::identity();
+        for row in 0..N {
+            for col in 0..row {
+                lower[row][col] = self.lu[row][col];
+            }
+        }
+        lower
+    }
+
+    pub fn upper(&self) -> Matrix<T, N, N> {
+        let mut upper = Matrix::new([Vector::default(); N]);
+        for row in 0..N {
+            for col in row..N {
+                upper[row][col] = self.lu[row][col];
+            }
+        }
+        upper
+    }
+
+    //row i of lower * upper is row permutation[i] of the decomposed matrix
+    pub fn permutation(&self) -> [usize; N] {
+        self.permutation
+    }
+
+    pub fn determinant(&self) -> T {
+        let mut determinant = T::one();
+        for i in 0..N {
+            determinant *= self.lu[i][i];
+        }
+        if self.even {
+            determinant
+        } else {
+            T::zero() - determinant
+        }
+    }
+
+    //solves matrix * x = b
+    pub fn solve(&self, b: Vector<T, N>) -> Vector<T, N> {
+        let mut x = Vector::default();
+
+        for row in 0..N {
+            let mut value = b[self.permutation[row]];
+            for col in 0..row {
+                value -= self.lu[row][col] * x[col];
+            }
+            x[row] = value;
+        }
+
+        for row in (0..N).rev() {
+            let mut value = x[row];
+            for col in row + 1..N {
+                value -= self.lu[row][col] * x[col];
+            }
+            x[row] = value / self.lu[row][row];
+        }
+
+        x
+    }
+
+    pub fn inverse(&self) -> Matrix<T, N, N> {
+        let mut inverse = Matrix::new([Vector::default(); N]);
+
+        for col in 0..N {
+            let mut unit = Vector::default();
+            unit[col] = T::one();
+
+            let column = self.solve(unit);
+
+            for row in 0..N {
+                inverse[row][col] = column[row];
+            }
+        }
+
+        inverse
+    }
+}
+
+//householder qr of a matrix with at least as many rows as columns, in the
+//thin form where q has orthonormal columns and r is square upper triangular
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Qr<T, const N: usize, const M: usize>
+where
+    T: Numeric,
+{
+    q: Matrix<T, N, M>,
+    r: Matrix<T, N, N>,
+}
+
+impl<T, const N: usize, const M: usize> Qr<T, N, M>
+where
+    T: Float,
+{
+    //fails to compile if the matrix has fewer rows than columns
+    pub fn new(matrix: Matrix<T, N, M>) -> Self {
+        const { assert!(M >= N, "qr needs at least as many rows as columns") };
+
+        let mut r = matrix;
+        let mut q = Matrix::<T, M, M>::identity();
+
+        for k in 0..N {
+            let mut norm = T::zero();
+            for row in k..M {
+                norm += r[row][k] * r[row][k];
+            }
+            let norm = norm.sqrt();
+            if norm == T::zero() {
+                continue;
+            }
+
+            //reflects column k onto the axis, picking the sign that avoids cancellation
+            let alpha = if r[k][k] > T::zero() { -norm } else { norm };
+            let mut v = Vector::<T, M>::default();
+            for row in k..M {
+                v[row] = r[row][k];
+            }
+            v[k] -= alpha;
+
+            let length_squared = v.magnitude_squared();
+            if length_squared == T::zero() {
+                continue;
+            }
+            let scale = (T::one() + T::one()) / length_squared;
+
+            for col in k..N {
+                let mut dot = T::zero();
+                for row in k..M {
+                    dot += v[row] * r[row][col];
+                }
+                for row in k..M {
+                    r[row][col] -= scale * dot * v[row];
+                }
+            }
+
+            for row in 0..M {
+                let mut dot = T::zero();
+                for i in k..M {
+                    dot += q[row][i] * v[i];
+                }
+                for i in k..M {
+                    q[row][i] -= scale * dot * v[i];
+                }
+            }
+        }
+
+        let mut thin_q = Matrix::<T, N, M>::new([Vector::default(); M]);
+        for row in 0..M {
+            for col in 0..N {
+                thin_q[row][col] = q[row][col];
+            }
+        }
+
+        let mut upper = Matrix::<T, N, N>::new([Vector::default(); N]);
+        for row in 0..N {
+            for col in row..N {
+                upper[row][col] = r[row][col];
+            }
+        }
+
+        Self {
+            q: thin_q,
+            r: upper,
+        }
+    }
+
+    pub fn q(&self) -> Matrix<T, N, M> {
+        self.q
+    }
+
+    pub fn r(&self) -> Matrix<T, N, N> {
+        self.r
+    }
+
+    //the x minimizing |matrix * x - b|, or none if the columns are not
+    //linearly independent
+    pub fn solve(&self, b: Vector<T, M>) -> Option<Vector<T, N>> {
+        let mut largest = T::zero();
+        for i in 0..N {
+            largest = largest.max(self.r[i][i].abs());
+        }
+        let tolerance = largest * T::EPSILON * T::from_f64(M as f64);
+
+        let mut x = Vector::<T, N>::default();
+        for row in 0..N {
+            for i in 0..M {
+                x[row] += self.q[i][row] * b[i];
+            }
+        }
+
+        for row in (0..N).rev() {
+            if self.r[row][row].abs() <= tolerance {
+                None?
+            }
+
+            let mut value = x[row];
+            for col in row + 1..N {
+                value -= self.r[row][col] * x[col];
+            }
+            x[row] = value / self.r[row][row];
+        }
+
+        Some(x)
+    }
+}
+
+//eigenvalues and eigenvectors of a symmetric 3x3 matrix, like an inertia
+//tensor or a covariance matrix, found with cyclic jacobi rotations
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SymmetricEigen<T>
+where
+    T: Numeric,
+{
+    //in decreasing order
+    pub values: Vector<T, 3>,
+    //column i is the unit eigenvector for value i. the columns form a
+    //rotation, the last one being negated where sorting made a reflection
+    pub vectors: Matrix<T, 3, 3>,
+}
+
+impl<T> SymmetricEigen<T>
+where
+    T: Float,
+{
+    //only the upper triangle of matrix is read
+    pub fn new(matrix: Matrix<T, 3, 3>) -> Self {
+        const SWEEPS: usize = 32;
+
+        let mut a = matrix;
+        for row in 0..3 {
+            for col in 0..row {
+                a[row][col] = a[col][row];
+            }
+        }
+        let mut vectors = Matrix::<T, 3, 3>::identity();
+
+        for _ in 0..SWEEPS {
+            let off_diagonal = a[0][1].abs() + a[0][2].abs() + a[1][2].abs();
+            let diagonal = a[0][0].abs() + a[1][1].abs() + a[2][2].abs();
+            if off_diagonal <= diagonal * T::EPSILON {
+                break;
+            }
+
+            for (p, q) in [(0, 1), (0, 2), (1, 2)] {
+                if a[p][q] == T::zero() {
+                    continue;
+                }
+
+                //the rotation in the pq plane that zeroes a[p][q]
+                let theta = (a[q][q] - a[p][p]) / (a[p][q] * (T::one() + T::one()));
+                let t = T::one() / (theta.abs() + (theta * theta + T::one()).sqrt());
+                let t = if theta < T::zero() { -t } else { t };
+                let cos = T::one() / (t * t + T::one()).sqrt();
+                let sin = t * cos;
+
+                let mut rotation = Matrix::<T, 3, 3>::identity();
+                rotation[p][p] = cos;
+                rotation[q][q] = cos;
+                rotation[p][q] = sin;
+                rotation[q][p] = -sin;
+
+                a = rotation.transpose() * a * rotation;
+                a[p][q] = T::zero();
+                a[q][p] = T::zero();
+                vectors = vectors * rotation;
+            }
+        }
+
+        let mut order = [0, 1, 2];
+        order.sort_by(|i, j| a[*j][*j].partial_cmp(&a[*i][*i]).unwrap_or(Ordering::Equal));
+
+        let mut values = Vector::default();
+        let mut sorted = Matrix::default();
+        for (col, source) in order.into_iter().enumerate() {
+            values[col] = a[source][source];
+            for row in 0..3 {
+                sorted[row][col] = vectors[row][source];
+            }
+        }
+
+        //an odd permutation of the columns flips the handedness
+        if sorted.determinant() < T::zero() {
+            for row in 0..3 {
+                sorted[row][2] = -sorted[row][2];
+            }
+        }
+
+        Self {
+            values,
+            vectors: sorted,
+        }
+    }
+}
@@ -0,0 +1,394 @@
+//! `Fixed<I, FRAC>` stores a number as an integer `I` counting steps of
+//! 2^-FRAC, so every operation is integer arithmetic and gives the same bits
+//! on every platform and JS engine. It implements `Float`, so vectors,
+//! matrices and quaternions of it use the same code as `f32`.
+//!
+//! The operators wrap on overflow in every build, keeping debug and release
+//! identical, and the `saturating_` methods clamp instead. Division by zero
+//! panics like it does for integers. Trigonometry interpolates tables built
+//! at compile time with integer arithmetic and is accurate to about 1e-6
+//! whatever FRAC is. `PI` needs FRAC of at most 29 for `i32` and 60 for `i64`.
+
+use std::{
+    cmp::Ordering,
+    fmt,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+use crate::{Float, One, Zero};
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Hash)]
+#[repr(transparent)]
+pub struct Fixed<I, const FRAC: u32> {
+    bits: I,
+}
+
+#[allow(non_camel_case_types)]
+pub type Q16_16 = Fixed<i32, 16>;
+#[allow(non_camel_case_types)]
+pub type Q32_32 = Fixed<i64, 32>;
+
+impl<I, const FRAC: u32> Fixed<I, FRAC> {
+    pub const fn from_bits(bits: I) -> Self {
+        Self { bits }
+    }
+}
+
+impl<I, const FRAC: u32> Fixed<I, FRAC>
+where
+    I: Copy,
+{
+    pub fn to_bits(self) -> I {
+        self.bits
+    }
+}
+
+//the tables below and their lookups work in q30 or q60, meaning 30 or 60
+//fraction bits held in an i128, and convert to FRAC at the end
+const TABLE_BITS: u32 = 10;
+const TABLE_SIZE: usize = 1 << TABLE_BITS;
+const ONE_Q60: i128 = 1 << 60;
+const PI_Q61: i128 = 7244019458077122842;
+const PI_Q30: i128 = ((PI_Q61 >> 30) + 1) >> 1;
+//2^60 / 2pi, turning radians into a fraction of a turn
+const TURNS_PER_RADIAN_Q60: i128 = 183493156455125077;
+
+const fn sin_q60(x: i128) -> i128 {
+    let x2 = (x * x) >> 60;
+    let (mut term, mut sum, mut n) = (x, x, 1);
+    while term != 0 {
+        term = -((term * x2) >> 60) / ((n + 1) * (n + 2));
+        sum += term;
+        n += 2;
+    }
+    sum
+}
+
+//halves the angle twice so the series converges quickly
+const fn atan_q60(t: i128) -> i128 {
+    let mut t = t;
+    let mut halvings = 0;
+    while halvings < 2 {
+        let root = (((ONE_Q60 + ((t * t) >> 60)) as u128) << 60).isqrt() as i128;
+        t = (t << 60) / (ONE_Q60 + root);
+        halvings += 1;
+    }
+
+    let t2 = (t * t) >> 60;
+    let (mut power, mut sum, mut n) = (t, t, 1);
+    while power != 0 {
+        power = -((power * t2) >> 60);
+        n += 2;
+        sum += power / n;
+    }
+    sum << halvings
+}
+
+const fn q60_to_q30(x: i128) -> i32 {
+    (((x >> 29) + 1) >> 1) as i32
+}
+
+//sin across a quarter turn
+const SINES: [i32; TABLE_SIZE + 1] = {
+    let mut table = [0; TABLE_SIZE + 1];
+    let mut i = 0;
+    while i <= TABLE_SIZE {
+        table[i] = q60_to_q30(sin_q60(PI_Q61 / 4 * i as i128 / TABLE_SIZE as i128));
+        i += 1;
+    }
+    table
+};
+
+//atan across 0..1
+const ARCTANGENTS: [i32; TABLE_SIZE + 1] = {
+    let mut table = [0; TABLE_SIZE + 1];
+    let mut i = 0;
+    while i <= TABLE_SIZE {
+        table[i] = q60_to_q30(atan_q60(ONE_Q60 * i as i128 / TABLE_SIZE as i128));
+        i += 1;
+    }
+    table
+};
+
+//position is a q30 fraction of the way along the table
+fn lookup(table: &[i32; TABLE_SIZE + 1], position: i128) -> i128 {
+    const FRACTION_BITS: u32 = 30 - TABLE_BITS;
+    let index = (position >> FRACTION_BITS) as usize;
+    let fraction = position & ((1 << FRACTION_BITS) - 1);
+    let (a, b) = (
+        table[index] as i128,
+        table[(index + 1).min(TABLE_SIZE)] as i128,
+    );
+    a + (((b - a) * fraction) >> FRACTION_BITS)
+}
+
+//a fraction of a turn in 32 bits, wrapping so whole turns fall away
+fn phase(bits: i128, frac: u32) -> u32 {
+    ((bits * TURNS_PER_RADIAN_Q60) >> (frac + 28)) as u32
+}
+
+fn sin_q30(phase: u32) -> i128 {
+    let position = (phase & ((1 << 30) - 1)) as i128;
+    match phase >> 30 {
+        0 => lookup(&SINES, position),
+        1 => lookup(&SINES, (1 << 30) - position),
+        2 => -lookup(&SINES, position),
+        _ => -lookup(&SINES, (1 << 30) - position),
+    }
+}
+
+fn atan2_q30(y: i128, x: i128) -> i128 {
+    let (y_abs, x_abs) = (y.abs(), x.abs());
+    if y_abs == 0 && x_abs == 0 {
+        return 0;
+    }
+
+    //reduced to the first octant, then reflected back out
+    let steep = y_abs > x_abs;
+    let ratio = if steep {
+        (x_abs << 30) / y_abs
+    } else {
+        (y_abs << 30) / x_abs
+    };
+
+    let mut angle = lookup(&ARCTANGENTS, ratio);
+    if steep {
+        angle = PI_Q30 / 2 - angle;
+    }
+    if x < 0 {
+        angle = PI_Q30 - angle;
+    }
+    if y < 0 {
+        -angle
+    } else {
+        angle
+    }
+}
+
+fn from_q30(value: i128, frac: u32) -> i128 {
+    match frac.cmp(&30) {
+        Ordering::Less => ((value >> (29 - frac)) + 1) >> 1,
+        Ordering::Equal => value,
+        Ordering::Greater => value << (frac - 30),
+    }
+}
+
+macro_rules! fixed {
+    ($($raw:ident => $wide:ident),*) => {
+        $(
+            impl<const FRAC: u32> Fixed<$raw, FRAC> {
+                pub const MIN: Self = Self::from_bits($raw::MIN);
+                pub const MAX: Self = Self::from_bits($raw::MAX);
+
+                const FRACTION_MASK: $raw = (((1 as $wide) << FRAC) - 1) as $raw;
+
+                pub fn from_int(value: $raw) -> Self {
+                    Self::from_bits(value.wrapping_shl(FRAC))
+                }
+
+                //rounds toward negative infinity
+                pub fn to_int(self) -> $raw {
+                    self.bits >> FRAC
+                }
+
+                fn saturate(wide: $wide) -> Self {
+                    Self::from_bits(wide.clamp($raw::MIN as $wide, $raw::MAX as $wide) as $raw)
+                }
+
+                //rounds to nearest, with halves rounding up
+                fn wide_mul(self, rhs: Self) -> $wide {
+                    let product = self.bits as $wide * rhs.bits as $wide;
+                    if FRAC == 0 {
+                        product
+                    } else {
+                        (product + (1 << (FRAC - 1))) >> FRAC
+                    }
+                }
+
+                //rounds toward zero
+                fn wide_div(self, rhs: Self) -> $wide {
+                    ((self.bits as $wide) << FRAC) / rhs.bits as $wide
+                }
+
+                pub fn saturating_add(self, rhs: Self) -> Self {
+                    Self::from_bits(self.bits.saturating_add(rhs.bits))
+                }
+
+                pub fn saturating_sub(self, rhs: Self) -> Self {
+                    Self::from_bits(self.bits.saturating_sub(rhs.bits))
+                }
+
+                pub fn saturating_mul(self, rhs: Self) -> Self {
+                    Self::saturate(self.wide_mul(rhs))
+                }
+
+                //dividing by zero saturates toward the sign of self
+                pub fn saturating_div(self, rhs: Self) -> Self {
+                    match (rhs.bits == 0, self.bits.cmp(&0)) {
+                        (true, Ordering::Less) => Self::MIN,
+                        (true, Ordering::Equal) => Self::zero(),
+                        (true, Ordering::Greater) => Self::MAX,
+                        (false, _) => Self::saturate(self.wide_div(rhs)),
+                    }
+                }
+            }
+
+            impl<const FRAC: u32> Add for Fixed<$raw, FRAC> {
+                type Output = Self;
+
+                fn add(self, rhs: Self) -> Self::Output {
+                    Self::from_bits(self.bits.wrapping_add(rhs.bits))
+                }
+            }
+
+            impl<const FRAC: u32> Sub for Fixed<$raw, FRAC> {
+                type Output = Self;
+
+                fn sub(self, rhs: Self) -> Self::Output {
+                    Self::from_bits(self.bits.wrapping_sub(rhs.bits))
+                }
+            }
+
+            impl<const FRAC: u32> Mul for Fixed<$raw, FRAC> {
+                type Output = Self;
+
+                fn mul(self, rhs: Self) -> Self::Output {
+                    Self::from_bits(self.wide_mul(rhs) as $raw)
+                }
+            }
+
+            impl<const FRAC: u32> Div for Fixed<$raw, FRAC> {
+                type Output = Self;
+
+                fn div(self, rhs: Self) -> Self::Output {
+                    Self::from_bits(self.wide_div(rhs) as $raw)
+                }
+            }
+
+            impl<const FRAC: u32> Neg for Fixed<$raw, FRAC> {
+                type Output = Self;
+
+                fn neg(self) -> Self::Output {
+                    Self::from_bits(self.bits.wrapping_neg())
+                }
+            }
+
+            impl<const FRAC: u32> AddAssign for Fixed<$raw, FRAC> {
+                fn add_assign(&mut self, rhs: Self) {
+                    *self = *self + rhs;
+                }
+            }
+
+            impl<const FRAC: u32> SubAssign for Fixed<$raw, FRAC> {
+                fn sub_assign(&mut self, rhs: Self) {
+                    *self = *self - rhs;
+                }
+            }
+
+            impl<const FRAC: u32> MulAssign for Fixed<$raw, FRAC> {
+                fn mul_assign(&mut self, rhs: Self) {
+                    *self = *self * rhs;
+                }
+            }
+
+            impl<const FRAC: u32> DivAssign for Fixed<$raw, FRAC> {
+                fn div_assign(&mut self, rhs: Self) {
+                    *self = *self / rhs;
+                }
+            }
+
+            impl<const FRAC: u32> Zero for Fixed<$raw, FRAC> {
+                fn zero() -> Self {
+                    Self::from_bits(0)
+                }
+            }
+
+            impl<const FRAC: u32> One for Fixed<$raw, FRAC> {
+                fn one() -> Self {
+                    Self::from_int(1)
+                }
+            }
+
+            impl<const FRAC: u32> fmt::Debug for Fixed<$raw, FRAC> {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt::Debug::fmt(&self.to_f64(), f)
+                }
+            }
+
+            impl<const FRAC: u32> fmt::Display for Fixed<$raw, FRAC> {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt::Display::fmt(&self.to_f64(), f)
+                }
+            }
+
+            //the saturated extremes stand in for the infinities
+            impl<const FRAC: u32> Float for Fixed<$raw, FRAC> {
+                const EPSILON: Self = Self::from_bits(1);
+                const INFINITY: Self = Self::MAX;
+                const PI: Self = Self::from_bits((((PI_Q61 >> (60 - FRAC)) + 1) >> 1) as $raw);
+
+                fn from_f64(value: f64) -> Self {
+                    Self::from_bits((value * (1_u128 << FRAC) as f64).round() as $raw)
+                }
+
+                fn to_f64(self) -> f64 {
+                    self.bits as f64 / (1_u128 << FRAC) as f64
+                }
+
+                //negative values have no root and give zero
+                fn sqrt(self) -> Self {
+                    if self.bits <= 0 {
+                        return Self::zero();
+                    }
+                    Self::from_bits(((self.bits as u128) << FRAC).isqrt() as $raw)
+                }
+
+                fn sin(self) -> Self {
+                    let sin = sin_q30(phase(self.bits as i128, FRAC));
+                    Self::from_bits(from_q30(sin, FRAC) as $raw)
+                }
+
+                fn cos(self) -> Self {
+                    let quarter = 1 << 30;
+                    let cos = sin_q30(phase(self.bits as i128, FRAC).wrapping_add(quarter));
+                    Self::from_bits(from_q30(cos, FRAC) as $raw)
+                }
+
+                fn tan(self) -> Self {
+                    self.sin().saturating_div(self.cos())
+                }
+
+                fn asin(self) -> Self {
+                    let one = Self::one();
+                    let x = self.clamp(-one, one);
+                    x.atan2((one - x * x).sqrt())
+                }
+
+                fn acos(self) -> Self {
+                    let one = Self::one();
+                    let x = self.clamp(-one, one);
+                    (one - x * x).sqrt().atan2(x)
+                }
+
+                fn atan2(self, x: Self) -> Self {
+                    let angle = atan2_q30(self.bits as i128, x.bits as i128);
+                    Self::from_bits(from_q30(angle, FRAC) as $raw)
+                }
+
+                fn floor(self) -> Self {
+                    Self::from_bits(self.bits & !Self::FRACTION_MASK)
+                }
+
+                fn ceil(self) -> Self {
+                    Self::from_bits(self.bits.wrapping_add(Self::FRACTION_MASK) & !Self::FRACTION_MASK)
+                }
+
+                fn is_finite(self) -> bool {
+                    self != Self::MAX && self != Self::MIN
+                }
+            }
+        )*
+    };
+}
+
+fixed!(i32 => i64, i64 => i128);
@@ -0,0 +1,502 @@
+#![allow(clippy::needless_range_loop)]
+
+use crate::{matrix::Matrix, quaternion::Quaternion, vector::Vector, Float, Numeric};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Aabb<T, const N: usize>
+where
+    T: Numeric,
+{
+    pub min: Vector<T, N>,
+    pub max: Vector<T, N>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Sphere<T, const N: usize>
+where
+    T: Numeric,
+{
+    pub center: Vector<T, N>,
+    pub radius: T,
+}
+
+//the points p where normal.dot(p) + distance is zero,
+//with normal pointing to the positive side
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Plane<T, const N: usize>
+where
+    T: Numeric,
+{
+    pub normal: Vector<T, N>,
+    pub distance: T,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Ray<T, const N: usize>
+where
+    T: Numeric,
+{
+    pub origin: Vector<T, N>,
+    pub direction: Vector<T, N>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Triangle<T>
+where
+    T: Numeric,
+{
+    pub a: Vector<T, 3>,
+    pub b: Vector<T, 3>,
+    pub c: Vector<T, 3>,
+}
+
+//planes are ordered left, right, bottom, top, near, far and face inwards
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Frustum<T>
+where
+    T: Numeric,
+{
+    pub planes: [Plane<T, 3>; 6],
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Obb<T>
+where
+    T: Numeric,
+{
+    pub center: Vector<T, 3>,
+    pub half_extents: Vector<T, 3>,
+    pub rotation: Quaternion<T>,
+}
+
+impl<T, const N: usize> Aabb<T, N>
+where
+    T: Numeric,
+{
+    pub fn new(min: Vector<T, N>, max: Vector<T, N>) -> Self {
+        Self { min, max }
+    }
+}
+
+impl<T, const N: usize> Sphere<T, N>
+where
+    T: Numeric,
+{
+    pub fn new(center: Vector<T, N>, radius: T) -> Self {
+        Self { center, radius }
+    }
+}
+
+impl<T, const N: usize> Plane<T, N>
+where
+    T: Numeric,
+{
+    pub fn new(normal: Vector<T, N>, distance: T) -> Self {
+        Self { normal, distance }
+    }
+}
+
+impl<T, const N: usize> Ray<T, N>
+where
+    T: Numeric,
+{
+    pub fn new(origin: Vector<T, N>, direction: Vector<T, N>) -> Self {
+        Self { origin, direction }
+    }
+}
+
+impl<T> Triangle<T>
+where
+    T: Numeric,
+{
+    pub fn new(a: Vector<T, 3>, b: Vector<T, 3>, c: Vector<T, 3>) -> Self {
+        Self { a, b, c }
+    }
+}
+
+impl<T> Obb<T>
+where
+    T: Numeric,
+{
+    pub fn new(center: Vector<T, 3>, half_extents: Vector<T, 3>, rotation: Quaternion<T>) -> Self {
+        Self {
+            center,
+            half_extents,
+            rotation,
+        }
+    }
+}
+
+impl<T, const N: usize> Aabb<T, N>
+where
+    T: Float,
+{
+    pub fn from_points(points: &[Vector<T, N>]) -> Option<Self> {
+        let (first, rest) = points.split_first()?;
+        let mut aabb = Self::new(*first, *first);
+        for point in rest {
+            aabb = aabb.include(*point);
+        }
+        Some(aabb)
+    }
+
+    pub fn center(&self) -> Vector<T, N> {
+        (self.min + self.max) * T::from_f64(0.5)
+    }
+
+    pub fn half_extents(&self) -> Vector<T, N> {
+        (self.max - self.min) * T::from_f64(0.5)
+    }
+
+    pub fn include(self, point: Vector<T, N>) -> Self {
+        Self::new(self.min.min(point), self.max.max(point))
+    }
+
+    pub fn union(self, other: Self) -> Self {
+        self.include(other.min).include(other.max)
+    }
+
+    pub fn contains_point(&self, point: Vector<T, N>) -> bool {
+        (0..N).all(|i| self.min[i] <= point[i] && point[i] <= self.max[i])
+    }
+
+    pub fn contains_aabb(&self, other: &Self) -> bool {
+        self.contains_point(other.min) && self.contains_point(other.max)
+    }
+
+    pub fn intersects_aabb(&self, other: &Self) -> bool {
+        (0..N).all(|i| self.min[i] <= other.max[i] && other.min[i] <= self.max[i])
+    }
+
+    pub fn closest_point(&self, point: Vector<T, N>) -> Vector<T, N> {
+        point.clamp(self.min, self.max)
+    }
+
+    pub fn distance_squared(&self, point: Vector<T, N>) -> T {
+        self.closest_point(point).distance_squared(&point)
+    }
+}
+
+impl<T, const N: usize> Sphere<T, N>
+where
+    T: Float,
+{
+    pub fn contains_point(&self, point: Vector<T, N>) -> bool {
+        self.center.distance_squared(&point) <= self.radius * self.radius
+    }
+
+    pub fn intersects_sphere(&self, other: &Self) -> bool {
+        let radius = self.radius + other.radius;
+        self.center.distance_squared(&other.center) <= radius * radius
+    }
+
+    pub fn intersects_aabb(&self, aabb: &Aabb<T, N>) -> bool {
+        aabb.distance_squared(self.center) <= self.radius * self.radius
+    }
+
+    //points inside the sphere are their own closest point
+    pub fn closest_point(&self, point: Vector<T, N>) -> Vector<T, N> {
+        if self.contains_point(point) {
+            return point;
+        }
+        self.center + (point - self.center).normalize() * self.radius
+    }
+}
+
+impl<T, const N: usize> Plane<T, N>
+where
+    T: Float,
+{
+    pub fn from_point_normal(point: Vector<T, N>, normal: Vector<T, N>) -> Self {
+        Self::new(normal, -normal.dot(point))
+    }
+
+    pub fn normalize(self) -> Self {
+        let magnitude = self.normal.magnitude();
+        Self::new(self.normal / magnitude, self.distance / magnitude)
+    }
+
+    //positive on the side normal points to, assumes normal is normalized
+    pub fn signed_distance(&self, point: Vector<T, N>) -> T {
+        self.normal.dot(point) + self.distance
+    }
+
+    pub fn closest_point(&self, point: Vector<T, N>) -> Vector<T, N> {
+        point - self.normal * self.signed_distance(point)
+    }
+}
+
+impl<T, const N: usize> Ray<T, N>
+where
+    T: Float,
+{
+    pub fn at(&self, t: T) -> Vector<T, N> {
+        self.origin + self.direction * t
+    }
+
+    pub fn closest_point(&self, point: Vector<T, N>) -> Vector<T, N> {
+        let t = (point - self.origin).dot(self.direction) / self.direction.dot(self.direction);
+        self.at(t.max(T::zero()))
+    }
+
+    //distances are returned in multiples of direction
+    pub fn intersects_aabb(&self, aabb: &Aabb<T, N>) -> Option<T> {
+        let mut near = T::zero();
+        let mut far = T::INFINITY;
+
+        for i in 0..N {
+            if self.direction[i] == T::zero() {
+                if self.origin[i] < aabb.min[i] || self.origin[i] > aabb.max[i] {
+                    None?
+                }
+                continue;
+            }
+
+            let inverse = T::one() / self.direction[i];
+            let mut t0 = (aabb.min[i] - self.origin[i]) * inverse;
+            let mut t1 = (aabb.max[i] - self.origin[i]) * inverse;
+            if t0 > t1 {
+                std::mem::swap(&mut t0, &mut t1);
+            }
+
+            near = near.max(t0);
+            far = far.min(t1);
+
+            if near > far {
+                None?
+            }
+        }
+
+        Some(near)
+    }
+
+    pub fn intersects_sphere(&self, sphere: &Sphere<T, N>) -> Option<T> {
+        let offset = self.origin - sphere.center;
+        let a = self.direction.dot(self.direction);
+        let b = offset.dot(self.direction);
+        let c = offset.dot(offset) - sphere.radius * sphere.radius;
+
+        let discriminant = b * b - a * c;
+        if discriminant < T::zero() {
+            None?
+        }
+
+        let root = discriminant.sqrt();
+        let near = (-b - root) / a;
+        let far = (-b + root) / a;
+
+        if far < T::zero() {
+            None?
+        }
+
+        Some(near.max(T::zero()))
+    }
+
+    pub fn intersects_plane(&self, plane: &Plane<T, N>) -> Option<T> {
+        let denominator = plane.normal.dot(self.direction);
+        if denominator == T::zero() {
+            None?
+        }
+
+        let t = -(plane.normal.dot(self.origin) + plane.distance) / denominator;
+        if t < T::zero() {
+            None?
+        }
+
+        Some(t)
+    }
+}
+
+impl<T> Ray<T, 3>
+where
+    T: Float,
+{
+    //moller trumbore, hitting either side of the triangle
+    pub fn intersects_triangle(&self, triangle: &Triangle<T>) -> Option<T> {
+        let edge1 = triangle.b - triangle.a;
+        let edge2 = triangle.c - triangle.a;
+
+        let p = self.direction.cross(edge2);
+        let determinant = edge1.dot(p);
+        if determinant.abs() < T::EPSILON {
+            None?
+        }
+
+        let inverse = T::one() / determinant;
+        let s = self.origin - triangle.a;
+
+        let u = s.dot(p) * inverse;
+        if u < T::zero() || u > T::one() {
+            None?
+        }
+
+        let q = s.cross(edge1);
+        let v = self.direction.dot(q) * inverse;
+        if v < T::zero() || u + v > T::one() {
+            None?
+        }
+
+        let t = edge2.dot(q) * inverse;
+        if t < T::zero() {
+            None?
+        }
+
+        Some(t)
+    }
+
+    pub fn intersects_obb(&self, obb: &Obb<T>) -> Option<T> {
+        let inverse = obb.rotation.conjugate();
+        let local = Ray::new(
+            inverse * (self.origin - obb.center),
+            inverse * self.direction,
+        );
+        let bounds = Aabb::new(-obb.half_extents, obb.half_extents);
+        local.intersects_aabb(&bounds)
+    }
+}
+
+impl<T> Triangle<T>
+where
+    T: Float,
+{
+    pub fn normal(&self) -> Vector<T, 3> {
+        (self.b - self.a).cross(self.c - self.a).normalize()
+    }
+
+    pub fn area(&self) -> T {
+        (self.b - self.a).cross(self.c - self.a).magnitude() * T::from_f64(0.5)
+    }
+
+    pub fn plane(&self) -> Plane<T, 3> {
+        Plane::<T, 3>::from_point_normal(self.a, self.normal())
+    }
+
+    //from real-time collision detection, section 5.1.5
+    pub fn closest_point(&self, point: Vector<T, 3>) -> Vector<T, 3> {
+        let (a, b, c) = (self.a, self.b, self.c);
+
+        let ab = b - a;
+        let ac = c - a;
+        let ap = point - a;
+        let d1 = ab.dot(ap);
+        let d2 = ac.dot(ap);
+        if d1 <= T::zero() && d2 <= T::zero() {
+            return a;
+        }
+
+        let bp = point - b;
+        let d3 = ab.dot(bp);
+        let d4 = ac.dot(bp);
+        if d3 >= T::zero() && d4 <= d3 {
+            return b;
+        }
+
+        let vc = d1 * d4 - d3 * d2;
+        if vc <= T::zero() && d1 >= T::zero() && d3 <= T::zero() {
+            return a + ab * (d1 / (d1 - d3));
+        }
+
+        let cp = point - c;
+        let d5 = ab.dot(cp);
+        let d6 = ac.dot(cp);
+        if d6 >= T::zero() && d5 <= d6 {
+            return c;
+        }
+
+        let vb = d5 * d2 - d1 * d6;
+        if vb <= T::zero() && d2 >= T::zero() && d6 <= T::zero() {
+            return a + ac * (d2 / (d2 - d6));
+        }
+
+        let va = d3 * d6 - d5 * d4;
+        if va <= T::zero() && d4 - d3 >= T::zero() && d5 - d6 >= T::zero() {
+            return b + (c - b) * ((d4 - d3) / ((d4 - d3) + (d5 - d6)));
+        }
+
+        let denominator = T::one() / (va + vb + vc);
+        a + ab * (vb * denominator) + ac * (vc * denominator)
+    }
+}
+
+impl<T> Frustum<T>
+where
+    T: Float,
+{
+    //extracts the planes of a view projection matrix with 0..1 depth
+    pub fn from_matrix(matrix: Matrix<T, 4, 4>) -> Self {
+        let plane = |row: Vector<T, 4>| {
+            Plane::<T, 3>::new(Vector::new([row[0], row[1], row[2]]), row[3]).normalize()
+        };
+
+        let [x, y, z, w] = *matrix;
+
+        Self {
+            planes: [
+                plane(w + x),
+                plane(w - x),
+                plane(w + y),
+                plane(w - y),
+                plane(z),
+                plane(w - z),
+            ],
+        }
+    }
+
+    pub fn contains_point(&self, point: Vector<T, 3>) -> bool {
+        self.planes
+            .iter()
+            .all(|plane| plane.signed_distance(point) >= T::zero())
+    }
+
+    //conservative, may accept spheres just outside the corners
+    pub fn intersects_sphere(&self, sphere: &Sphere<T, 3>) -> bool {
+        self.planes
+            .iter()
+            .all(|plane| plane.signed_distance(sphere.center) >= -sphere.radius)
+    }
+
+    //conservative, may accept boxes just outside the corners
+    pub fn intersects_aabb(&self, aabb: &Aabb<T, 3>) -> bool {
+        self.planes.iter().all(|plane| {
+            let mut positive = aabb.min;
+            for i in 0..3 {
+                if plane.normal[i] >= T::zero() {
+                    positive[i] = aabb.max[i];
+                }
+            }
+            plane.signed_distance(positive) >= T::zero()
+        })
+    }
+}
+
+impl<T> Obb<T>
+where
+    T: Float,
+{
+    fn local(&self, point: Vector<T, 3>) -> Vector<T, 3> {
+        self.rotation.conjugate() * (point - self.center)
+    }
+
+    pub fn contains_point(&self, point: Vector<T, 3>) -> bool {
+        let local = self.local(point);
+        (0..3).all(|i| local[i].abs() <= self.half_extents[i])
+    }
+
+    pub fn closest_point(&self, point: Vector<T, 3>) -> Vector<T, 3> {
+        let local = self
+            .local(point)
+            .clamp(-self.half_extents, self.half_extents);
+        self.center + self.rotation * local
+    }
+
+    pub fn aabb(&self) -> Aabb<T, 3> {
+        let rotation = self.rotation.to_matrix3();
+        let mut extents = Vector::<T, 3>::default();
+        for row in 0..3 {
+            for col in 0..3 {
+                extents[row] += rotation[row][col].abs() * self.half_extents[col];
+            }
+        }
+        Aabb::new(self.center - extents, self.center + extents)
+    }
+}
@@ -1,23 +1,57 @@
 #![feature(convert_float_to_int)]
+#![feature(min_specialization)]
 
-use std::ops::{Add, AddAssign, Div, DivAssign, Mul, Sub, SubAssign};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 
+pub mod angle;
+pub mod color;
+pub mod curve;
+pub mod decomposition;
+pub mod fixed;
+pub mod geometry;
 pub mod matrix;
+pub mod noise;
+pub mod pod;
+pub mod quaternion;
+pub mod random;
+#[cfg(feature = "serde")]
+mod serialize;
+#[cfg(feature = "simd")]
+mod simd;
+pub mod transform;
 pub mod vector;
 
 pub mod prelude {
-    pub use crate::{matrix::Matrix, vector::Vector};
+    pub use crate::{
+        angle::{Degrees, EulerRot, Radians},
+        color::{Hsla, LinearRgba, Oklab, Srgba},
+        curve::{ArcLength, BSpline, CatmullRom, CubicBezier, Curve, Hermite, QuadraticBezier},
+        fixed::{Fixed, Q16_16, Q32_32},
+        geometry::{Aabb, Frustum, Obb, Plane, Ray, Sphere, Triangle},
+        matrix::Matrix,
+        noise::{Fbm, Noise, OpenSimplex2, Perlin, Ridged, Worley},
+        pod::{Pod, Zeroable},
+        quaternion::Quaternion,
+        random::Pcg32,
+        transform::Transform,
+        vector::Vector,
+        Float, One, Zero,
+    };
 }
 
+//neg is left to float so unsigned integers can still be vector components
 pub trait Numeric:
-    Add
+    Add<Output = Self>
     + AddAssign
-    + Sub
+    + Sub<Output = Self>
     + SubAssign
-    + Mul
-    + SubAssign
-    + Div
+    + Mul<Output = Self>
+    + MulAssign
+    + Div<Output = Self>
     + DivAssign
+    + PartialOrd
+    + Zero
+    + One
     + Clone
     + Copy
     + Default
@@ -26,17 +60,188 @@ pub trait Numeric:
 }
 
 impl<T> Numeric for T where
-    T: Add
+    T: Add<Output = T>
         + AddAssign
-        + Sub
-        + SubAssign
-        + Mul
+        + Sub<Output = T>
         + SubAssign
-        + Div
+        + Mul<Output = T>
+        + MulAssign
+        + Div<Output = T>
         + DivAssign
+        + PartialOrd
+        + Zero
+        + One
         + Clone
         + Copy
         + Default
         + std::fmt::Debug
 {
 }
+
+pub trait Zero {
+    fn zero() -> Self;
+}
+
+pub trait One {
+    fn one() -> Self;
+}
+
+macro_rules! identities {
+    ($zero:literal, $one:literal: $($type:ty),*) => {
+        $(
+            impl Zero for $type {
+                fn zero() -> Self {
+                    $zero
+                }
+            }
+
+            impl One for $type {
+                fn one() -> Self {
+                    $one
+                }
+            }
+        )*
+    };
+}
+
+identities!(0, 1: u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+identities!(0.0, 1.0: f32, f64);
+
+pub trait Float: Numeric + Neg<Output = Self> {
+    const EPSILON: Self;
+    const INFINITY: Self;
+    const PI: Self;
+
+    //for constants that are not worth naming, like 0.5
+    fn from_f64(value: f64) -> Self;
+    fn to_f64(self) -> f64;
+
+    fn sqrt(self) -> Self;
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    fn tan(self) -> Self;
+    fn asin(self) -> Self;
+    fn acos(self) -> Self;
+    fn atan2(self, x: Self) -> Self;
+    fn floor(self) -> Self;
+    fn ceil(self) -> Self;
+    fn is_finite(self) -> bool;
+
+    fn sin_cos(self) -> (Self, Self) {
+        (self.sin(), self.cos())
+    }
+
+    fn abs(self) -> Self {
+        if self < Self::zero() {
+            -self
+        } else {
+            self
+        }
+    }
+
+    fn min(self, other: Self) -> Self {
+        if other < self {
+            other
+        } else {
+            self
+        }
+    }
+
+    fn max(self, other: Self) -> Self {
+        if other > self {
+            other
+        } else {
+            self
+        }
+    }
+
+    fn clamp(self, min: Self, max: Self) -> Self {
+        self.max(min).min(max)
+    }
+
+    fn lerp(self, other: Self, t: Self) -> Self {
+        self + (other - self) * t
+    }
+}
+
+macro_rules! float {
+    ($($float:ident),*) => {
+        $(
+            impl Float for $float {
+                const EPSILON: Self = $float::EPSILON;
+                const INFINITY: Self = $float::INFINITY;
+                const PI: Self = std::$float::consts::PI;
+
+                fn from_f64(value: f64) -> Self {
+                    value as $float
+                }
+
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+
+                fn sqrt(self) -> Self {
+                    $float::sqrt(self)
+                }
+
+                fn sin(self) -> Self {
+                    $float::sin(self)
+                }
+
+                fn cos(self) -> Self {
+                    $float::cos(self)
+                }
+
+                fn tan(self) -> Self {
+                    $float::tan(self)
+                }
+
+                fn asin(self) -> Self {
+                    $float::asin(self)
+                }
+
+                fn acos(self) -> Self {
+                    $float::acos(self)
+                }
+
+                fn atan2(self, x: Self) -> Self {
+                    $float::atan2(self, x)
+                }
+
+                fn floor(self) -> Self {
+                    $float::floor(self)
+                }
+
+                fn ceil(self) -> Self {
+                    $float::ceil(self)
+                }
+
+                fn is_finite(self) -> bool {
+                    $float::is_finite(self)
+                }
+
+                fn sin_cos(self) -> (Self, Self) {
+                    $float::sin_cos(self)
+                }
+
+                fn abs(self) -> Self {
+                    $float::abs(self)
+                }
+
+                fn min(self, other: Self) -> Self {
+                    $float::min(self, other)
+                }
+
+                fn max(self, other: Self) -> Self {
+                    $float::max(self, other)
+                }
+
+                fn clamp(self, min: Self, max: Self) -> Self {
+                    $float::clamp(self, min, max)
+                }
+            }
+        )*
+    };
+}
+
+float!(f32, f64);
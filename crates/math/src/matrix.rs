@@ -1,8 +1,24 @@
+//! `Matrix<T, N, M>` has `M` rows of `N` columns and is stored row-major,
+//! so `matrix[row][col]` indexes a single element. Vectors are columns and
+//! are transformed as `matrix * vector`, which means `a * b` applies `b` first.
+//!
+//! WGSL reads matrices column-major, so a row-major matrix uploaded as-is
+//! arrives transposed and shaders multiply with the vector on the left.
+//!
+//! The transform constructors are right-handed with the camera looking down
+//! `-z`, and projections map depth into WebGPU's `0..1` range.
+
 #![allow(clippy::needless_range_loop)]
 
-use std::ops::{Add, AddAssign, Deref, DerefMut, Div, Mul, Sub, SubAssign};
+use std::ops::{Add, AddAssign, Deref, DerefMut, Mul, Sub, SubAssign};
 
-use crate::{vector::Vector, Numeric};
+use crate::{
+    angle::Radians,
+    decomposition::{abs, Epsilon, Lu, Qr, SymmetricEigen},
+    quaternion::Quaternion,
+    vector::Vector,
+    Float, Numeric,
+};
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 #[repr(transparent)]
@@ -25,19 +41,128 @@ where
 impl<T, const N: usize> Matrix<T, N, N>
 where
     T: Numeric,
-    T: From<u8>,
 {
     pub fn identity() -> Self {
         let mut data = [Vector::default(); N];
 
         for i in (0..N * N).step_by(N + 1) {
-            data[i % N][i / N] = 1_u8.into()
+            data[i % N][i / N] = T::one()
         }
 
         Self { data }
     }
 }
 
+impl<T> Matrix<T, 4, 4>
+where
+    T: Numeric,
+{
+    pub fn translation(translation: Vector<T, 3>) -> Self {
+        let mut matrix = Self::identity();
+        for row in 0..3 {
+            matrix[row][3] = translation[row];
+        }
+        matrix
+    }
+
+    pub fn scale(scale: Vector<T, 3>) -> Self {
+        let mut matrix = Self::identity();
+        for i in 0..3 {
+            matrix[i][i] = scale[i];
+        }
+        matrix
+    }
+}
+
+impl<T> Matrix<T, 4, 4>
+where
+    T: Numeric,
+{
+    //assumes rotation is normalized
+    pub fn rotation(rotation: Quaternion<T>) -> Self {
+        rotation.to_matrix4()
+    }
+}
+
+impl<T> Matrix<T, 4, 4>
+where
+    T: Float,
+{
+    //fov_y is the vertical field of view
+    pub fn perspective(fov_y: impl Into<Radians<T>>, aspect: T, near: T, far: T) -> Self {
+        let (zero, one) = (T::zero(), T::one());
+        let f = one / (fov_y.into() * T::from_f64(0.5)).tan();
+        let depth = far / (near - far);
+        Self::new([
+            Vector::new([f / aspect, zero, zero, zero]),
+            Vector::new([zero, f, zero, zero]),
+            Vector::new([zero, zero, depth, near * depth]),
+            Vector::new([zero, zero, -one, zero]),
+        ])
+    }
+
+    pub fn orthographic(left: T, right: T, bottom: T, top: T, near: T, far: T) -> Self {
+        let (zero, one) = (T::zero(), T::one());
+        let two = one + one;
+        let width = right - left;
+        let height = top - bottom;
+        let depth = near - far;
+        Self::new([
+            Vector::new([two / width, zero, zero, -(right + left) / width]),
+            Vector::new([zero, two / height, zero, -(top + bottom) / height]),
+            Vector::new([zero, zero, one / depth, near / depth]),
+            Vector::new([zero, zero, zero, one]),
+        ])
+    }
+
+    //view matrix for a camera at eye facing target
+    pub fn look_at(eye: Vector<T, 3>, target: Vector<T, 3>, up: Vector<T, 3>) -> Self {
+        let (zero, one) = (T::zero(), T::one());
+        let forward = (target - eye).normalize();
+        let side = forward.cross(up).normalize();
+        let up = side.cross(forward);
+        Self::new([
+            Vector::new([side[0], side[1], side[2], -side.dot(eye)]),
+            Vector::new([up[0], up[1], up[2], -up.dot(eye)]),
+            Vector::new([-forward[0], -forward[1], -forward[2], forward.dot(eye)]),
+            Vector::new([zero, zero, zero, one]),
+        ])
+    }
+
+    pub fn rotation_x(angle: impl Into<Radians<T>>) -> Self {
+        let (zero, one) = (T::zero(), T::one());
+        let (sin, cos) = angle.into().sin_cos();
+        Self::new([
+            Vector::new([one, zero, zero, zero]),
+            Vector::new([zero, cos, -sin, zero]),
+            Vector::new([zero, sin, cos, zero]),
+            Vector::new([zero, zero, zero, one]),
+        ])
+    }
+
+    pub fn rotation_y(angle: impl Into<Radians<T>>) -> Self {
+        let (zero, one) = (T::zero(), T::one());
+        let (sin, cos) = angle.into().sin_cos();
+        Self::new([
+            Vector::new([cos, zero, sin, zero]),
+            Vector::new([zero, one, zero, zero]),
+            Vector::new([-sin, zero, cos, zero]),
+            Vector::new([zero, zero, zero, one]),
+        ])
+    }
+
+    pub fn rotation_z(angle: impl Into<Radians<T>>) -> Self {
+        let (zero, one) = (T::zero(), T::one());
+        let (sin, cos) = angle.into().sin_cos();
+        Self::new([
+            Vector::new([cos, -sin, zero, zero]),
+            Vector::new([sin, cos, zero, zero]),
+            Vector::new([zero, zero, one, zero]),
+            Vector::new([zero, zero, zero, one]),
+        ])
+    }
+}
+
 impl<T: Default, const N: usize, const M: usize> Default for Matrix<T, N, M>
 where
     T: Numeric,
@@ -70,47 +195,148 @@ where
 impl<T, const N: usize> Matrix<T, N, N>
 where
     T: Numeric,
-    T: From<u8>,
-    T: Div<Output = T>,
-    T: Mul<Output = T>,
-    T: PartialOrd,
 {
-    pub fn inverse(mut self) -> Self {
-        let mut data = *Self::identity();
-
-        //apply guass jordan elimination
+    pub fn trace(self) -> T {
+        let mut trace = T::default();
         for i in 0..N {
-            if self.data[i][i] == T::default() {
-                panic!("attempt to divide by zero")
-            }
+            trace += self.data[i][i];
+        }
+        trace
+    }
+}
 
-            for j in 0..N {
-                if i != j {
-                    let ratio = self.data[j][i] / self.data[i][i];
-                    for k in 0..N {
-                        let value = self.data[i][k];
-                        self.data[j][k] -= ratio * value;
-                    }
-                    for k in 0..N {
-                        let value = data[i][k];
-                        data[j][k] -= ratio * value;
-                    }
-                }
+impl<T, const N: usize> Matrix<T, N, N>
+where
+    T: Numeric,
+{
+    pub fn lu(self) -> Option<Lu<T, N>> {
+        Lu::new(self)
+    }
+
+    pub fn determinant(self) -> T {
+        self.lu().map_or(T::default(), |lu| lu.determinant())
+    }
+
+    pub fn try_inverse(self) -> Option<Self> {
+        Some(self.lu()?.inverse())
+    }
+
+    pub fn inverse(self) -> Self {
+        self.try_inverse().expect("matrix is not invertible")
+    }
+
+    //solves self * x = b, returning none if self is singular
+    pub fn solve(self, b: Vector<T, N>) -> Option<Vector<T, N>> {
+        Some(self.lu()?.solve(b))
+    }
+}
+
+impl<T, const N: usize, const M: usize> Matrix<T, N, M>
+where
+    T: Float,
+{
+    pub fn qr(self) -> Qr<T, N, M> {
+        Qr::new(self)
+    }
+
+    //the x minimizing |self * x - b|, for overdetermined systems
+    pub fn least_squares(self, b: Vector<T, M>) -> Option<Vector<T, N>> {
+        self.qr().solve(b)
+    }
+}
+
+impl<T> Matrix<T, 3, 3>
+where
+    T: Float,
+{
+    pub fn symmetric_eigen(self) -> SymmetricEigen<T> {
+        SymmetricEigen::new(self)
+    }
+}
+
+impl<T> Matrix<T, 3, 3>
+where
+    T: Numeric,
+{
+    //cofactor expansion, cheaper than going through lu for 3x3
+    fn try_inverse3(self) -> Option<Self> {
+        let m = self.data;
+
+        let cofactors = [
+            [
+                m[1][1] * m[2][2] - m[1][2] * m[2][1],
+                m[1][2] * m[2][0] - m[1][0] * m[2][2],
+                m[1][0] * m[2][1] - m[1][1] * m[2][0],
+            ],
+            [
+                m[0][2] * m[2][1] - m[0][1] * m[2][2],
+                m[0][0] * m[2][2] - m[0][2] * m[2][0],
+                m[0][1] * m[2][0] - m[0][0] * m[2][1],
+            ],
+            [
+                m[0][1] * m[1][2] - m[0][2] * m[1][1],
+                m[0][2] * m[1][0] - m[0][0] * m[1][2],
+                m[0][0] * m[1][1] - m[0][1] * m[1][0],
+            ],
+        ];
+
+        let determinant =
+            m[0][0] * cofactors[0][0] + m[0][1] * cofactors[0][1] + m[0][2] * cofactors[0][2];
+
+        //each product in the determinant is rounded, so a singular matrix can be
+        //left with a determinant of a few epsilon times the products' size
+        let a = |row: usize, col: usize| abs(m[row][col]);
+        let size = a(0, 0) * (a(1, 1) * a(2, 2) + a(1, 2) * a(2, 1))
+            + a(0, 1) * (a(1, 2) * a(2, 0) + a(1, 0) * a(2, 2))
+            + a(0, 2) * (a(1, 0) * a(2, 1) + a(1, 1) * a(2, 0));
+
+        let mut tolerance = T::default();
+        for _ in 0..3 {
+            tolerance += size * T::epsilon();
+        }
+
+        if abs(determinant) <= tolerance {
+            None?
+        }
+
+        //the inverse is the transposed cofactor matrix over the determinant
+        let mut data = [Vector::default(); 3];
+        for row in 0..3 {
+            for col in 0..3 {
+                data[row][col] = cofactors[col][row] / determinant;
             }
         }
 
-        //row operation ot make principal diagonal element equal to 1
-        for i in 0..N {
-            let divisor = self.data[i][i];
-            for j in 0..N {
-                self.data[i][j] /= divisor;
+        Some(Self { data })
+    }
+}
+
+impl<T> Matrix<T, 4, 4>
+where
+    T: Numeric,
+{
+    //inverts a transform whose bottom row is [0, 0, 0, 1]
+    pub fn try_affine_inverse(self) -> Option<Self> {
+        let mut linear = Matrix::<T, 3, 3>::default();
+        for row in 0..3 {
+            for col in 0..3 {
+                linear[row][col] = self.data[row][col];
             }
-            for j in 0..N {
-                data[i][j] /= divisor;
+        }
+
+        let linear = linear.try_inverse3()?;
+
+        let mut data = self.data;
+        for row in 0..3 {
+            let mut translation = T::default();
+            for col in 0..3 {
+                data[row][col] = linear[row][col];
+                translation -= linear[row][col] * self.data[col][3];
             }
+            data[row][3] = translation;
         }
 
-        Self { data }
+        Some(Self { data })
     }
 }
 
@@ -198,12 +424,10 @@ where
 impl<T, const A: usize, const B: usize, const C: usize> Mul<Matrix<T, C, A>> for Matrix<T, A, B>
 where
     T: Numeric,
-    T: Add<Output = T>,
-    T: Mul<Output = T>,
 {
     type Output = Matrix<T, C, B>;
 
-    fn mul(self, rhs: Matrix<T, C, A>) -> Self::Output {
+    default fn mul(self, rhs: Matrix<T, C, A>) -> Self::Output {
         let mut data = [Vector::default(); B];
         for row in 0..B {
             for col in 0..C {
@@ -221,19 +445,17 @@ where
 impl<T, const A: usize, const B: usize> Mul<Vector<T, A>> for Matrix<T, A, B>
 where
     T: Numeric,
-    T: Add<Output = T>,
-    T: Mul<Output = T>,
 {
     type Output = Vector<T, B>;
 
-    fn mul(self, rhs: Vector<T, A>) -> Self::Output {
+    default fn mul(self, rhs: Vector<T, A>) -> Self::Output {
         let mut data = [T::default(); B];
         for row in 0..B {
             let mut cell = T::default();
             for i in 0..A {
                 cell += self.data[row][i] * rhs[i];
-                data[row] = cell;
             }
+            data[row] = cell;
         }
         Vector::<T, B>::new(data)
     }
@@ -0,0 +1,406 @@
+//! Seedable coherent noise over `Vector<f32, 2>`, `Vector<f32, 3>` and
+//! `Vector<f32, 4>`.
+//!
+//! Lattice points are hashed from the seed rather than looked up in a
+//! permutation table, so patterns do not repeat every 256 units. Gradient
+//! noises return values in roughly -1..1 and are zero on their lattice
+//! points; `Worley` returns a distance.
+
+#![allow(clippy::needless_range_loop)]
+
+use crate::vector::Vector;
+
+pub trait Noise<const N: usize> {
+    fn sample(&self, point: Vector<f32, N>) -> f32;
+}
+
+//splitmix64's finalizer
+fn mix(mut x: u64) -> u64 {
+    x = (x ^ (x >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94d049bb133111eb);
+    x ^ (x >> 31)
+}
+
+fn hash<const N: usize>(seed: u64, cell: [i32; N]) -> u64 {
+    let mut hash = seed;
+    for coordinate in cell {
+        hash = mix(hash.wrapping_add(0x9e3779b97f4a7c15) ^ coordinate as u32 as u64);
+    }
+    hash
+}
+
+//the edges of the hypercube, with the diagonals added in 2d where the
+//edges alone would leave only the four axes
+fn gradient<const N: usize>(hash: u64) -> [f32; N] {
+    const DIAGONALS: [[f32; 2]; 8] = [
+        [1.0, 0.0],
+        [-1.0, 0.0],
+        [0.0, 1.0],
+        [0.0, -1.0],
+        [1.0, 1.0],
+        [-1.0, 1.0],
+        [1.0, -1.0],
+        [-1.0, -1.0],
+    ];
+
+    let mut gradient = [0.0; N];
+    if N == 2 {
+        gradient.copy_from_slice(&DIAGONALS[(hash % 8) as usize]);
+        return gradient;
+    }
+
+    let zero = (hash % N as u64) as usize;
+    let mut signs = hash / N as u64;
+    for axis in 0..N {
+        if axis != zero {
+            gradient[axis] = if signs & 1 == 0 { 1.0 } else { -1.0 };
+            signs >>= 1;
+        }
+    }
+    gradient
+}
+
+fn dot<const N: usize>(a: [f32; N], b: [f32; N]) -> f32 {
+    let mut sum = 0.0;
+    for i in 0..N {
+        sum += a[i] * b[i];
+    }
+    sum
+}
+
+fn floor<const N: usize>(point: [f32; N]) -> ([i32; N], [f32; N]) {
+    let cell = point.map(|x| x.floor());
+    let mut local = point;
+    for i in 0..N {
+        local[i] -= cell[i];
+    }
+    (cell.map(|x| x as i32), local)
+}
+
+//Ken Perlin's gradient noise, interpolated with his quintic fade
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Perlin {
+    pub seed: u64,
+}
+
+impl Perlin {
+    pub fn new(seed: u64) -> Self {
+        Self { seed }
+    }
+}
+
+fn fade(t: f32) -> f32 {
+    t * t * t * (t * (t * 6.0 - 15.0) + 10.0)
+}
+
+macro_rules! perlin {
+    ($($n:literal => $scale:literal),*) => {
+        $(
+            impl Noise<$n> for Perlin {
+                fn sample(&self, point: Vector<f32, $n>) -> f32 {
+                    const CORNERS: usize = 1 << $n;
+
+                    let (cell, local) = floor(*point);
+
+                    //bit i of a corner is its offset along axis i
+                    let mut values = [0.0; CORNERS];
+                    for corner in 0..CORNERS {
+                        let mut lattice = cell;
+                        let mut offset = local;
+                        for axis in 0..$n {
+                            if corner >> axis & 1 == 1 {
+                                lattice[axis] += 1;
+                                offset[axis] -= 1.0;
+                            }
+                        }
+                        values[corner] = dot(gradient(hash(self.seed, lattice)), offset);
+                    }
+
+                    //collapses one axis at a time, halving the corners each time
+                    let mut count = CORNERS;
+                    for axis in 0..$n {
+                        let t = fade(local[axis]);
+                        count /= 2;
+                        for i in 0..count {
+                            let (a, b) = (values[2 * i], values[2 * i + 1]);
+                            values[i] = a + (b - a) * t;
+                        }
+                    }
+
+                    values[0] * $scale
+                }
+            }
+        )*
+    };
+}
+
+//scales found by sampling, bringing each dimension to roughly -1..1
+perlin!(2 => 1.0, 3 => 1.0, 4 => 0.85);
+
+//the lattices of K.jpg's OpenSimplex2: simplex in 2d and 4d, and in 3d a
+//rotated body centered cubic lattice that hides the axis aligned artifacts
+//simplex shows there. contributions fall off as (0.5 - d²)⁴. hashing and
+//gradients are this module's, so values differ from the reference code
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct OpenSimplex2 {
+    pub seed: u64,
+}
+
+impl OpenSimplex2 {
+    pub fn new(seed: u64) -> Self {
+        Self { seed }
+    }
+}
+
+fn falloff<const N: usize>(radius_squared: f32, hash: u64, offset: [f32; N]) -> f32 {
+    let a = radius_squared - dot(offset, offset);
+    if a <= 0.0 {
+        return 0.0;
+    }
+    let a = a * a;
+    a * a * dot(gradient(hash), offset)
+}
+
+//the N + 1 corners of the simplex containing point, found by ranking
+//the skewed offsets. a radius of 0.5 is the simplex's height, so no
+//lattice point outside it reaches in
+fn simplex<const N: usize>(seed: u64, point: [f32; N]) -> f32 {
+    let dimensions = N as f32;
+    let skew = ((dimensions + 1.0).sqrt() - 1.0) / dimensions;
+    let unskew = (1.0 - 1.0 / (dimensions + 1.0).sqrt()) / dimensions;
+
+    let mut skewed = point;
+    let s = point.iter().sum::<f32>() * skew;
+    for x in &mut skewed {
+        *x += s;
+    }
+
+    let (mut cell, local) = floor(skewed);
+    let t = local.iter().sum::<f32>() * unskew;
+    let mut offset = local.map(|x| x - t);
+
+    //corners step along axes in order of decreasing skewed offset
+    let mut order = [0; N];
+    for i in 0..N {
+        order[i] = i;
+    }
+    order.sort_by(|a, b| local[*b].total_cmp(&local[*a]));
+
+    let mut value = falloff(0.5, hash(seed, cell), offset);
+    for axis in order {
+        cell[axis] += 1;
+        offset[axis] -= 1.0;
+        for x in &mut offset {
+            *x += unskew;
+        }
+        value += falloff(0.5, hash(seed, cell), offset);
+    }
+    value
+}
+
+impl Noise<2> for OpenSimplex2 {
+    fn sample(&self, point: Vector<f32, 2>) -> f32 {
+        simplex(self.seed, *point) * 68.0
+    }
+}
+
+impl Noise<3> for OpenSimplex2 {
+    fn sample(&self, point: Vector<f32, 3>) -> f32 {
+        let [x, y, z] = *point;
+
+        //rotates the lattice so its main diagonal points along an axis
+        let r = (x + y + z) * (2.0 / 3.0);
+        let rotated = [r - x, r - y, r - z];
+
+        let mut cell = rotated.map(|x| x.round() as i32);
+        let mut offset = [0.0; 3];
+        for i in 0..3 {
+            offset[i] = rotated[i] - cell[i] as f32;
+        }
+
+        //each of the two cubic lattices gives its closest point and the
+        //next closest along the axis the point is furthest out on. any
+        //third point is at least 0.5 away, so falls outside the radius
+        let mut value = 0.0;
+        let mut seed = self.seed;
+        for lattice in 0..2 {
+            value += falloff(0.5, hash(seed, cell), offset);
+
+            let mut axis = 0;
+            for i in 1..3 {
+                if offset[i].abs() > offset[axis].abs() {
+                    axis = i;
+                }
+            }
+            let step = if offset[axis] < 0.0 { -1 } else { 1 };
+            let (mut neighbour, mut neighbour_offset) = (cell, offset);
+            neighbour[axis] += step;
+            neighbour_offset[axis] -= step as f32;
+            value += falloff(0.5, hash(seed, neighbour), neighbour_offset);
+
+            if lattice == 0 {
+                //the other lattice is offset by a half on every axis
+                for i in 0..3 {
+                    if offset[i] < 0.0 {
+                        cell[i] -= 1;
+                        offset[i] += 0.5;
+                    } else {
+                        offset[i] -= 0.5;
+                    }
+                }
+                seed = mix(seed ^ 0x5bd1e995);
+            }
+        }
+
+        value * 74.0
+    }
+}
+
+impl Noise<4> for OpenSimplex2 {
+    fn sample(&self, point: Vector<f32, 4>) -> f32 {
+        simplex(self.seed, *point) * 60.0
+    }
+}
+
+//cellular noise with one jittered feature point per unit cell
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Worley {
+    pub seed: u64,
+}
+
+impl Worley {
+    pub fn new(seed: u64) -> Self {
+        Self { seed }
+    }
+
+    //the distances to the closest and second closest feature points
+    pub fn distances<const N: usize>(&self, point: Vector<f32, N>) -> (f32, f32) {
+        let (cell, local) = floor(*point);
+        let (mut first, mut second) = (f32::INFINITY, f32::INFINITY);
+
+        //every cell within one step on each axis, counted in base 3
+        for neighbour in 0..3_usize.pow(N as u32) {
+            let mut lattice = cell;
+            let mut offset = local;
+            let mut digits = neighbour;
+            for axis in 0..N {
+                let step = (digits % 3) as i32 - 1;
+                digits /= 3;
+                lattice[axis] += step;
+                offset[axis] -= step as f32;
+            }
+
+            let cell_hash = hash(self.seed, lattice);
+            for axis in 0..N {
+                let jitter = (mix(cell_hash ^ axis as u64) >> 40) as f32 / (1 << 24) as f32;
+                offset[axis] -= jitter;
+            }
+
+            let distance = dot(offset, offset);
+            if distance < first {
+                second = first;
+                first = distance;
+            } else if distance < second {
+                second = distance;
+            }
+        }
+
+        (first.sqrt(), second.sqrt())
+    }
+}
+
+//the distance to the closest feature point
+impl<const N: usize> Noise<N> for Worley {
+    fn sample(&self, point: Vector<f32, N>) -> f32 {
+        self.distances(point).0
+    }
+}
+
+//fractal brownian motion, summing octaves of source at rising frequency
+//and falling amplitude, normalized back to the range of source
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Fbm<S> {
+    pub source: S,
+    pub octaves: u32,
+    //frequency multiplier between octaves
+    pub lacunarity: f32,
+    //amplitude multiplier between octaves
+    pub gain: f32,
+}
+
+impl<S> Fbm<S> {
+    pub fn new(source: S, octaves: u32) -> Self {
+        Self {
+            source,
+            octaves,
+            lacunarity: 2.0,
+            gain: 0.5,
+        }
+    }
+}
+
+impl<S, const N: usize> Noise<N> for Fbm<S>
+where
+    S: Noise<N>,
+{
+    fn sample(&self, point: Vector<f32, N>) -> f32 {
+        let (mut value, mut total) = (0.0, 0.0);
+        let (mut frequency, mut amplitude) = (1.0, 1.0);
+        for _ in 0..self.octaves {
+            value += self.source.sample(point * frequency) * amplitude;
+            total += amplitude;
+            frequency *= self.lacunarity;
+            amplitude *= self.gain;
+        }
+
+        if total > 0.0 {
+            value / total
+        } else {
+            0.0
+        }
+    }
+}
+
+//fbm over 1 - |source|, squared, which turns the zero crossings of a
+//gradient noise into sharp ridges. returns -1..1 like its source
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Ridged<S> {
+    pub source: S,
+    pub octaves: u32,
+    pub lacunarity: f32,
+    pub gain: f32,
+}
+
+impl<S> Ridged<S> {
+    pub fn new(source: S, octaves: u32) -> Self {
+        Self {
+            source,
+            octaves,
+            lacunarity: 2.0,
+            gain: 0.5,
+        }
+    }
+}
+
+impl<S, const N: usize> Noise<N> for Ridged<S>
+where
+    S: Noise<N>,
+{
+    fn sample(&self, point: Vector<f32, N>) -> f32 {
+        let (mut value, mut total) = (0.0, 0.0);
+        let (mut frequency, mut amplitude) = (1.0, 1.0);
+        for _ in 0..self.octaves {
+            let ridge = 1.0 - self.source.sample(point * frequency).abs().min(1.0);
+            value += ridge * ridge * amplitude;
+            total += amplitude;
+            frequency *= self.lacunarity;
+            amplitude *= self.gain;
+        }
+
+        if total > 0.0 {
+            value / total * 2.0 - 1.0
+        } else {
+            0.0
+        }
+    }
+}
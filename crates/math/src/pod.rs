@@ -0,0 +1,129 @@
+//! Marker traits for types that can be viewed as raw bytes, so vertex and
+//! uniform data can be handed to the GPU without `unsafe` at the call site.
+//!
+//! `Zeroable` types are valid when every byte is zero. `Pod` types are also
+//! `Copy`, have no padding and are valid for every bit pattern. Structs of
+//! pod fields get both through the `pod!` macro, which checks this at
+//! compile time instead of asking for an `unsafe impl`.
+
+use std::{mem, slice};
+
+use crate::{
+    angle::{Degrees, Radians},
+    color::{LinearRgba, Srgba},
+    fixed::Fixed,
+    matrix::Matrix,
+    quaternion::Quaternion,
+    vector::Vector,
+    Numeric,
+};
+
+/// # Safety
+///
+/// The all zero bit pattern must be a valid value of the type.
+pub unsafe trait Zeroable: Sized {
+    fn zeroed() -> Self {
+        //sound by the contract of the trait
+        unsafe { mem::zeroed() }
+    }
+}
+
+/// # Safety
+///
+/// The type must have no padding, no pointers and no invalid bit patterns.
+pub unsafe trait Pod: Zeroable + Copy + 'static {}
+
+pub fn as_bytes<T>(data: &[T]) -> &[u8]
+where
+    T: Pod,
+{
+    //sound as pod types have no padding, so every byte is initialized
+    unsafe { slice::from_raw_parts(data.as_ptr().cast(), mem::size_of_val(data)) }
+}
+
+macro_rules! primitives {
+    ($($type:ty),*) => {
+        $(
+            unsafe impl Zeroable for $type {}
+            unsafe impl Pod for $type {}
+        )*
+    };
+}
+
+primitives!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+primitives!(LinearRgba, Srgba);
+
+unsafe impl<T, const N: usize> Zeroable for [T; N] where T: Zeroable {}
+unsafe impl<T, const N: usize> Pod for [T; N] where T: Pod {}
+
+//the math types are repr(transparent) over their components
+unsafe impl<T, const N: usize> Zeroable for Vector<T, N> where T: Numeric + Zeroable {}
+unsafe impl<T, const N: usize> Pod for Vector<T, N> where T: Numeric + Pod {}
+
+unsafe impl<T, const N: usize, const M: usize> Zeroable for Matrix<T, N, M> where
+    T: Numeric + Zeroable
+{
+}
+unsafe impl<T, const N: usize, const M: usize> Pod for Matrix<T, N, M> where T: Numeric + Pod {}
+
+unsafe impl<T> Zeroable for Quaternion<T> where T: Numeric + Zeroable {}
+unsafe impl<T> Pod for Quaternion<T> where T: Numeric + Pod {}
+
+unsafe impl<T> Zeroable for Radians<T> where T: Zeroable {}
+unsafe impl<T> Pod for Radians<T> where T: Pod {}
+
+unsafe impl<T> Zeroable for Degrees<T> where T: Zeroable {}
+unsafe impl<T> Pod for Degrees<T> where T: Pod {}
+
+unsafe impl<I, const FRAC: u32> Zeroable for Fixed<I, FRAC> where I: Zeroable {}
+unsafe impl<I, const FRAC: u32> Pod for Fixed<I, FRAC> where I: Pod {}
+
+/// Declares a `#[repr(C)]` struct and implements `Pod` and `Zeroable` for
+/// it, failing to compile if a field is not pod or the layout has padding.
+/// The struct must still derive `Clone` and `Copy`.
+///
+/// ```
+/// math::pod! {
+///     #[derive(Clone, Copy)]
+///     pub struct Uniforms {
+///         pub mvp: math::matrix::Matrix<f32, 4, 4>,
+///         pub tint: math::color::LinearRgba,
+///     }
+/// }
+///
+/// let uniforms = Uniforms {
+///     mvp: math::matrix::Matrix::identity(),
+///     tint: math::color::LinearRgba::WHITE,
+/// };
+/// assert_eq!(math::pod::as_bytes(&[uniforms]).len(), 80);
+/// ```
+#[macro_export]
+macro_rules! pod {
+    (
+        $(#[$attribute:meta])*
+        $visibility:vis struct $name:ident {
+            $($(#[$field_attribute:meta])* $field_visibility:vis $field:ident: $type:ty),* $(,)?
+        }
+    ) => {
+        $(#[$attribute])*
+        #[repr(C)]
+        $visibility struct $name {
+            $($(#[$field_attribute])* $field_visibility $field: $type),*
+        }
+
+        const _: () = {
+            fn assert_pod<T: $crate::pod::Pod>() {}
+            fn assert_fields() {
+                $(assert_pod::<$type>();)*
+            }
+            assert!(
+                ::std::mem::size_of::<$name>() == 0 $(+ ::std::mem::size_of::<$type>())*,
+                "pod structs cannot have padding"
+            );
+        };
+
+        //sound as every field is pod and the assertion above rules out padding
+        unsafe impl $crate::pod::Zeroable for $name {}
+        unsafe impl $crate::pod::Pod for $name {}
+    };
+}
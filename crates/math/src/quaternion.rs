@@ -0,0 +1,416 @@
+#![allow(clippy::needless_range_loop)]
+
+use std::ops::{Add, AddAssign, Deref, DerefMut, Mul, MulAssign, Sub, SubAssign};
+
+use crate::{
+    angle::{EulerRot, Radians},
+    matrix::Matrix,
+    vector::Vector,
+    Float, Numeric,
+};
+
+//stored as [x, y, z, w] where w is the scalar part
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct Quaternion<T>
+where
+    T: Numeric,
+{
+    data: [T; 4],
+}
+
+impl<T> Quaternion<T>
+where
+    T: Numeric,
+{
+    pub fn new(data: [T; 4]) -> Self {
+        Self { data }
+    }
+
+    pub fn from_parts(vector: Vector<T, 3>, scalar: T) -> Self {
+        Self {
+            data: [vector[0], vector[1], vector[2], scalar],
+        }
+    }
+
+    pub fn vector(self) -> Vector<T, 3> {
+        Vector::new([self.data[0], self.data[1], self.data[2]])
+    }
+
+    pub fn scalar(self) -> T {
+        self.data[3]
+    }
+
+    pub fn identity() -> Self {
+        let zero = T::default();
+        Self {
+            data: [zero, zero, zero, T::one()],
+        }
+    }
+
+    pub fn conjugate(self) -> Self {
+        let zero = T::default();
+        let [x, y, z, w] = self.data;
+        Self {
+            data: [zero - x, zero - y, zero - z, w],
+        }
+    }
+
+    pub fn dot(self, rhs: Self) -> T {
+        let mut dot = T::default();
+        for i in 0..4 {
+            dot += self.data[i] * rhs.data[i];
+        }
+        dot
+    }
+
+    pub fn magnitude_squared(self) -> T {
+        self.dot(self)
+    }
+
+    pub fn inverse(self) -> Self {
+        let magnitude_squared = self.magnitude_squared();
+        let mut data = self.conjugate().data;
+        for i in 0..4 {
+            data[i] /= magnitude_squared;
+        }
+        Self { data }
+    }
+
+    //assumes self is normalized
+    pub fn rotate(self, vector: Vector<T, 3>) -> Vector<T, 3> {
+        let two = T::one() + T::one();
+        let axis = self.vector();
+        let t = axis.cross(vector) * two;
+        vector + t * self.scalar() + axis.cross(t)
+    }
+
+    //assumes self is normalized
+    pub fn to_matrix3(self) -> Matrix<T, 3, 3> {
+        let one: T = T::one();
+        let two = T::one() + T::one();
+        let [x, y, z, w] = self.data;
+        Matrix::new([
+            Vector::new([
+                one - two * (y * y + z * z),
+                two * (x * y - z * w),
+                two * (x * z + y * w),
+            ]),
+            Vector::new([
+                two * (x * y + z * w),
+                one - two * (x * x + z * z),
+                two * (y * z - x * w),
+            ]),
+            Vector::new([
+                two * (x * z - y * w),
+                two * (y * z + x * w),
+                one - two * (x * x + y * y),
+            ]),
+        ])
+    }
+
+    //assumes self is normalized
+    pub fn to_matrix4(self) -> Matrix<T, 4, 4> {
+        let rotation = self.to_matrix3();
+        let mut matrix = Matrix::<T, 4, 4>::identity();
+        for row in 0..3 {
+            for col in 0..3 {
+                matrix[row][col] = rotation[row][col];
+            }
+        }
+        matrix
+    }
+}
+
+impl<T> Default for Quaternion<T>
+where
+    T: Numeric,
+{
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl<T> From<[T; 4]> for Quaternion<T>
+where
+    T: Numeric,
+{
+    fn from(data: [T; 4]) -> Self {
+        Self::new(data)
+    }
+}
+
+impl<T> Deref for Quaternion<T>
+where
+    T: Numeric,
+{
+    type Target = [T; 4];
+
+    fn deref(&self) -> &Self::Target {
+        &self.data
+    }
+}
+
+impl<T> DerefMut for Quaternion<T>
+where
+    T: Numeric,
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.data
+    }
+}
+
+impl<T> Add for Quaternion<T>
+where
+    T: Numeric,
+{
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let mut data = [T::default(); 4];
+        for i in 0..4 {
+            data[i] = self.data[i] + rhs.data[i];
+        }
+        Self { data }
+    }
+}
+
+impl<T> AddAssign for Quaternion<T>
+where
+    T: Numeric,
+{
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T> Sub for Quaternion<T>
+where
+    T: Numeric,
+{
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        let mut data = [T::default(); 4];
+        for i in 0..4 {
+            data[i] = self.data[i] - rhs.data[i];
+        }
+        Self { data }
+    }
+}
+
+impl<T> SubAssign for Quaternion<T>
+where
+    T: Numeric,
+{
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<T> Mul<T> for Quaternion<T>
+where
+    T: Numeric,
+{
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        let mut data = self.data;
+        for i in 0..4 {
+            data[i] *= rhs;
+        }
+        Self { data }
+    }
+}
+
+//hamilton product, rotating by rhs first and then by self
+impl<T> Mul for Quaternion<T>
+where
+    T: Numeric,
+{
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        let [ax, ay, az, aw] = self.data;
+        let [bx, by, bz, bw] = rhs.data;
+        Self {
+            data: [
+                aw * bx + ax * bw + ay * bz - az * by,
+                aw * by - ax * bz + ay * bw + az * bx,
+                aw * bz + ax * by - ay * bx + az * bw,
+                aw * bw - ax * bx - ay * by - az * bz,
+            ],
+        }
+    }
+}
+
+impl<T> MulAssign for Quaternion<T>
+where
+    T: Numeric,
+{
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<T> Mul<Vector<T, 3>> for Quaternion<T>
+where
+    T: Numeric,
+{
+    type Output = Vector<T, 3>;
+
+    fn mul(self, rhs: Vector<T, 3>) -> Self::Output {
+        self.rotate(rhs)
+    }
+}
+
+impl<T> From<Quaternion<T>> for Matrix<T, 3, 3>
+where
+    T: Numeric,
+{
+    fn from(quaternion: Quaternion<T>) -> Self {
+        quaternion.to_matrix3()
+    }
+}
+
+impl<T> From<Quaternion<T>> for Matrix<T, 4, 4>
+where
+    T: Numeric,
+{
+    fn from(quaternion: Quaternion<T>) -> Self {
+        quaternion.to_matrix4()
+    }
+}
+
+impl<T> Quaternion<T>
+where
+    T: Float,
+{
+    pub fn magnitude(self) -> T {
+        self.magnitude_squared().sqrt()
+    }
+
+    pub fn normalize(self) -> Self {
+        self * (T::one() / self.magnitude())
+    }
+
+    //axis must be normalized
+    pub fn from_axis_angle(axis: Vector<T, 3>, angle: impl Into<Radians<T>>) -> Self {
+        let (sin, cos) = (angle.into() * T::from_f64(0.5)).sin_cos();
+        Self::from_parts(axis * sin, cos)
+    }
+
+    //rotates about x, then y, then z
+    pub fn from_euler(
+        x: impl Into<Radians<T>>,
+        y: impl Into<Radians<T>>,
+        z: impl Into<Radians<T>>,
+    ) -> Self {
+        Self::from_euler_rot(EulerRot::XYZ, [x.into(), y.into(), z.into()])
+    }
+
+    //the rotation part of a matrix without scale
+    pub fn from_matrix3(matrix: Matrix<T, 3, 3>) -> Self {
+        let m = matrix;
+        let one = T::one();
+        let two = one + one;
+        let quarter = T::from_f64(0.25);
+        let trace = m[0][0] + m[1][1] + m[2][2];
+
+        let data = if trace > T::zero() {
+            let s = (trace + one).sqrt() * two;
+            [
+                (m[2][1] - m[1][2]) / s,
+                (m[0][2] - m[2][0]) / s,
+                (m[1][0] - m[0][1]) / s,
+                quarter * s,
+            ]
+        } else if m[0][0] > m[1][1] && m[0][0] > m[2][2] {
+            let s = (one + m[0][0] - m[1][1] - m[2][2]).sqrt() * two;
+            [
+                quarter * s,
+                (m[0][1] + m[1][0]) / s,
+                (m[0][2] + m[2][0]) / s,
+                (m[2][1] - m[1][2]) / s,
+            ]
+        } else if m[1][1] > m[2][2] {
+            let s = (one + m[1][1] - m[0][0] - m[2][2]).sqrt() * two;
+            [
+                (m[0][1] + m[1][0]) / s,
+                quarter * s,
+                (m[1][2] + m[2][1]) / s,
+                (m[0][2] - m[2][0]) / s,
+            ]
+        } else {
+            let s = (one + m[2][2] - m[0][0] - m[1][1]).sqrt() * two;
+            [
+                (m[0][2] + m[2][0]) / s,
+                (m[1][2] + m[2][1]) / s,
+                quarter * s,
+                (m[1][0] - m[0][1]) / s,
+            ]
+        };
+
+        Self { data }.normalize()
+    }
+
+    pub fn from_matrix4(matrix: Matrix<T, 4, 4>) -> Self {
+        let mut rotation = Matrix::<T, 3, 3>::default();
+        for row in 0..3 {
+            for col in 0..3 {
+                rotation[row][col] = matrix[row][col];
+            }
+        }
+        Self::from_matrix3(rotation)
+    }
+
+    //normalized linear interpolation along the shortest path
+    pub fn nlerp(self, other: Self, t: T) -> Self {
+        let other = if self.dot(other) < T::zero() {
+            other * -T::one()
+        } else {
+            other
+        };
+        (self * (T::one() - t) + other * t).normalize()
+    }
+
+    //spherical linear interpolation along the shortest path
+    pub fn slerp(self, other: Self, t: T) -> Self {
+        let mut cos = self.dot(other);
+        let mut other = other;
+
+        if cos < T::zero() {
+            cos = -cos;
+            other = other * -T::one();
+        }
+
+        //nearly parallel rotations would divide by a vanishing sine
+        if cos > T::one() - T::EPSILON * T::from_f64(16.0) {
+            return self.nlerp(other, t);
+        }
+
+        let angle = cos.acos();
+        let sin = angle.sin();
+        let a = ((T::one() - t) * angle).sin() / sin;
+        let b = (t * angle).sin() / sin;
+        self * a + other * b
+    }
+}
+
+impl<T> From<Matrix<T, 3, 3>> for Quaternion<T>
+where
+    T: Float,
+{
+    fn from(matrix: Matrix<T, 3, 3>) -> Self {
+        Self::from_matrix3(matrix)
+    }
+}
+
+impl<T> From<Matrix<T, 4, 4>> for Quaternion<T>
+where
+    T: Float,
+{
+    fn from(matrix: Matrix<T, 4, 4>) -> Self {
+        Self::from_matrix4(matrix)
+    }
+}
@@ -0,0 +1,232 @@
+//! A seedable `Pcg32` generator and the sampling built on it.
+//!
+//! Sampling only uses integer arithmetic and the correctly rounded float
+//! operations (`+ - * /` and `sqrt`), never `sin`, `cos` or `ln`, whose
+//! results vary between platform math libraries. A seed therefore produces
+//! the same values on wasm32 and x86_64.
+
+use std::ops::Range;
+
+use crate::{quaternion::Quaternion, vector::Vector};
+
+const MULTIPLIER: u64 = 6364136223846793005;
+const DEFAULT_STREAM: u64 = 0xda3e39cb94b95bdb;
+
+//pcg-xsh-rr with 64 bits of state and 32 bits of output
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Pcg32 {
+    state: u64,
+    increment: u64,
+}
+
+impl Pcg32 {
+    pub fn new(seed: u64) -> Self {
+        Self::with_stream(seed, DEFAULT_STREAM)
+    }
+
+    //generators with the same seed but different streams are independent
+    pub fn with_stream(seed: u64, stream: u64) -> Self {
+        let mut random = Self {
+            state: 0,
+            increment: (stream << 1) | 1,
+        };
+        random.step();
+        random.state = random.state.wrapping_add(seed);
+        random.step();
+        random
+    }
+
+    fn step(&mut self) {
+        self.state = self
+            .state
+            .wrapping_mul(MULTIPLIER)
+            .wrapping_add(self.increment);
+    }
+
+    pub fn next_u32(&mut self) -> u32 {
+        let state = self.state;
+        self.step();
+        let xorshifted = (((state >> 18) ^ state) >> 27) as u32;
+        xorshifted.rotate_right((state >> 59) as u32)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        let high = self.next_u32() as u64;
+        (high << 32) | self.next_u32() as u64
+    }
+
+    //uniform in 0..1, using as many bits as the mantissa holds
+    pub fn next_f32(&mut self) -> f32 {
+        (self.next_u32() >> 8) as f32 / (1 << 24) as f32
+    }
+
+    pub fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1_u64 << 53) as f64
+    }
+
+    pub fn next_bool(&mut self) -> bool {
+        self.next_u32() >> 31 == 1
+    }
+
+    //uniform in 0..bound without modulo bias, see Lemire's
+    //"Fast Random Integer Generation in an Interval"
+    fn below(&mut self, bound: u64) -> u64 {
+        let mut product = self.next_u64() as u128 * bound as u128;
+        if (product as u64) < bound {
+            let threshold = bound.wrapping_neg() % bound;
+            while (product as u64) < threshold {
+                product = self.next_u64() as u128 * bound as u128;
+            }
+        }
+        (product >> 64) as u64
+    }
+
+    //panics if range is empty
+    pub fn range<T>(&mut self, range: Range<T>) -> T
+    where
+        T: Uniform,
+    {
+        assert!(range.start < range.end, "cannot sample an empty range");
+        T::sample(self, range.start, range.end)
+    }
+
+    //uniform on the unit sphere, or the unit circle when N is 2
+    pub fn unit_vector<const N: usize>(&mut self) -> Vector<f32, N> {
+        const { assert!(N > 0, "a zero length vector has no direction") };
+        loop {
+            let vector = self.cube::<N>();
+            let magnitude_squared = vector.magnitude_squared();
+            //too close to the center and normalizing loses precision
+            if magnitude_squared > 1e-4 && magnitude_squared <= 1.0 {
+                return vector / magnitude_squared.sqrt();
+            }
+        }
+    }
+
+    //uniform inside the unit ball, or the unit disk when N is 2
+    pub fn in_sphere<const N: usize>(&mut self) -> Vector<f32, N> {
+        loop {
+            let vector = self.cube::<N>();
+            if vector.magnitude_squared() <= 1.0 {
+                return vector;
+            }
+        }
+    }
+
+    pub fn in_disk(&mut self) -> Vector<f32, 2> {
+        self.in_sphere()
+    }
+
+    //uniform in -1..1 on every axis
+    fn cube<const N: usize>(&mut self) -> Vector<f32, N> {
+        Vector::new([(); N].map(|_| self.next_f32() * 2.0 - 1.0))
+    }
+
+    //a uniformly distributed rotation, using Marsaglia's method
+    pub fn quaternion(&mut self) -> Quaternion<f32> {
+        let (first, second) = loop {
+            let first = self.in_disk();
+            let second = self.in_disk();
+            if second.magnitude_squared() > 0.0 {
+                break (first, second);
+            }
+        };
+
+        let (s, t) = (first.magnitude_squared(), second.magnitude_squared());
+        let scale = ((1.0 - s) / t).sqrt();
+
+        Quaternion::new([first[0], first[1], second[0] * scale, second[1] * scale])
+    }
+
+    //an index picked with probability proportional to its weight,
+    //or none if no weight is positive. negative weights count as zero
+    pub fn weighted(&mut self, weights: &[f32]) -> Option<usize> {
+        let total = weights.iter().map(|weight| weight.max(0.0)).sum::<f32>();
+        if total <= 0.0 {
+            None?
+        }
+
+        let mut target = self.next_f32() * total;
+        let mut last = None;
+        for (index, weight) in weights.iter().enumerate() {
+            if *weight <= 0.0 {
+                continue;
+            }
+            if target < *weight {
+                return Some(index);
+            }
+            target -= weight;
+            last = Some(index);
+        }
+
+        //rounding in the sum can leave target just past the end
+        last
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> Option<&'a T> {
+        if items.is_empty() {
+            None?
+        }
+        Some(&items[self.below(items.len() as u64) as usize])
+    }
+
+    //fisher-yates
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            let j = self.below(i as u64 + 1) as usize;
+            items.swap(i, j);
+        }
+    }
+}
+
+//types that can be sampled uniformly from a half open range
+pub trait Uniform: PartialOrd + Sized {
+    fn sample(random: &mut Pcg32, start: Self, end: Self) -> Self;
+}
+
+//spans are computed in 64 bits so usize samples the same on every target
+macro_rules! uniform_integer {
+    ($($type:ty => $unsigned:ty),*) => {
+        $(
+            impl Uniform for $type {
+                fn sample(random: &mut Pcg32, start: Self, end: Self) -> Self {
+                    let span = end.wrapping_sub(start) as $unsigned as u64;
+                    start.wrapping_add(random.below(span) as $type)
+                }
+            }
+        )*
+    };
+}
+
+uniform_integer!(
+    u8 => u8,
+    u16 => u16,
+    u32 => u32,
+    u64 => u64,
+    usize => usize,
+    i8 => u8,
+    i16 => u16,
+    i32 => u32,
+    i64 => u64,
+    isize => usize
+);
+
+macro_rules! uniform_float {
+    ($($type:ty => $next:ident),*) => {
+        $(
+            impl Uniform for $type {
+                fn sample(random: &mut Pcg32, start: Self, end: Self) -> Self {
+                    let sample = start + (end - start) * random.$next();
+                    //rounding can land on end, which the range excludes
+                    if sample < end {
+                        sample
+                    } else {
+                        start
+                    }
+                }
+            }
+        )*
+    };
+}
+
+uniform_float!(f32 => next_f32, f64 => next_f64);
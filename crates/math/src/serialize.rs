@@ -0,0 +1,238 @@
+//! Serde support behind the `serde` feature. Vectors and quaternions are
+//! plain arrays, matrices are arrays of rows and colors are `[r, g, b, a]`,
+//! so scene files stay readable and independent of the struct layouts.
+//! Angles are bare numbers and `EulerRot` is its name, like `"YXZ"`.
+//! `Fixed` stores its bits so snapshots round-trip exactly.
+
+use std::{fmt, marker::PhantomData};
+
+use serde::{
+    de::{Error, SeqAccess, Visitor},
+    ser::SerializeTuple,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::{
+    angle::{Degrees, EulerRot, Radians},
+    color::{Hsla, LinearRgba, Oklab, Srgba},
+    fixed::Fixed,
+    matrix::Matrix,
+    quaternion::Quaternion,
+    transform::Transform,
+    vector::Vector,
+    Numeric,
+};
+
+//serde only implements arrays up to 32 elements, not for every N
+fn serialize_array<S, T>(data: &[T], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: Serialize,
+{
+    let mut tuple = serializer.serialize_tuple(data.len())?;
+    for element in data {
+        tuple.serialize_element(element)?;
+    }
+    tuple.end()
+}
+
+struct ArrayVisitor<T, const N: usize>(PhantomData<T>);
+
+impl<'de, T, const N: usize> Visitor<'de> for ArrayVisitor<T, N>
+where
+    T: Deserialize<'de> + Default + Copy,
+{
+    type Value = [T; N];
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "an array of {N} elements")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut data = [T::default(); N];
+        for (i, element) in data.iter_mut().enumerate() {
+            *element = seq
+                .next_element()?
+                .ok_or_else(|| Error::invalid_length(i, &self))?;
+        }
+        Ok(data)
+    }
+}
+
+fn deserialize_array<'de, D, T, const N: usize>(deserializer: D) -> Result<[T; N], D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de> + Default + Copy,
+{
+    deserializer.deserialize_tuple(N, ArrayVisitor(PhantomData))
+}
+
+impl<T, const N: usize> Serialize for Vector<T, N>
+where
+    T: Numeric + Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_array(&self[..], serializer)
+    }
+}
+
+impl<'de, T, const N: usize> Deserialize<'de> for Vector<T, N>
+where
+    T: Numeric + Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_array(deserializer).map(Vector::new)
+    }
+}
+
+impl<T, const N: usize, const M: usize> Serialize for Matrix<T, N, M>
+where
+    T: Numeric + Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_array(&self[..], serializer)
+    }
+}
+
+impl<'de, T, const N: usize, const M: usize> Deserialize<'de> for Matrix<T, N, M>
+where
+    T: Numeric + Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_array(deserializer).map(Matrix::new)
+    }
+}
+
+impl<T> Serialize for Quaternion<T>
+where
+    T: Numeric + Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_array(&self[..], serializer)
+    }
+}
+
+impl<'de, T> Deserialize<'de> for Quaternion<T>
+where
+    T: Numeric + Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_array(deserializer).map(Quaternion::new)
+    }
+}
+
+//written as [translation, rotation, scale]
+impl<T> Serialize for Transform<T>
+where
+    T: Numeric + Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (self.translation, self.rotation, self.scale).serialize(serializer)
+    }
+}
+
+impl<'de, T> Deserialize<'de> for Transform<T>
+where
+    T: Numeric + Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (translation, rotation, scale) = Deserialize::deserialize(deserializer)?;
+        Ok(Self {
+            translation,
+            rotation,
+            scale,
+        })
+    }
+}
+
+macro_rules! color {
+    ($($type:ident => [$($field:ident),*]),*) => {
+        $(
+            impl Serialize for $type {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    serialize_array(&[$(self.$field),*], serializer)
+                }
+            }
+
+            impl<'de> Deserialize<'de> for $type {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    let [$($field),*] = deserialize_array::<D, f32, 4>(deserializer)?;
+                    Ok(Self { $($field),* })
+                }
+            }
+        )*
+    };
+}
+
+color!(
+    LinearRgba => [red, green, blue, alpha],
+    Srgba => [red, green, blue, alpha],
+    Hsla => [hue, saturation, lightness, alpha],
+    Oklab => [lightness, a, b, alpha]
+);
+
+impl<I, const FRAC: u32> Serialize for Fixed<I, FRAC>
+where
+    I: Copy + Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.to_bits().serialize(serializer)
+    }
+}
+
+impl<'de, I, const FRAC: u32> Deserialize<'de> for Fixed<I, FRAC>
+where
+    I: Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        I::deserialize(deserializer).map(Fixed::from_bits)
+    }
+}
+
+macro_rules! angle {
+    ($($type:ident),*) => {
+        $(
+            impl<T> Serialize for $type<T>
+            where
+                T: Serialize,
+            {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    self.0.serialize(serializer)
+                }
+            }
+
+            impl<'de, T> Deserialize<'de> for $type<T>
+            where
+                T: Deserialize<'de>,
+            {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    T::deserialize(deserializer).map($type)
+                }
+            }
+        )*
+    };
+}
+
+angle!(Radians, Degrees);
+
+const EULER_ROTS: [&str; 6] = ["XYZ", "XZY", "YXZ", "YZX", "ZXY", "ZYX"];
+
+impl Serialize for EulerRot {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let index = EulerRot::ALL.iter().position(|order| order == self);
+        serializer.serialize_str(EULER_ROTS[index.unwrap_or_default()])
+    }
+}
+
+impl<'de> Deserialize<'de> for EulerRot {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        match EULER_ROTS.iter().position(|order| *order == name) {
+            Some(index) => Ok(EulerRot::ALL[index]),
+            None => Err(D::Error::unknown_variant(&name, &EULER_ROTS)),
+        }
+    }
+}
@@ -0,0 +1,271 @@
+//! Specializes the `f32` 4-wide vector and 4x4 matrix operators onto SIMD
+//! registers, using SSE on x86_64 and `simd128` on wasm32 when the target
+//! enables it. Other targets keep the scalar path.
+//!
+//! Every lane performs the same operations in the same order as the scalar
+//! loops, with accumulators starting at zero and no fused multiply-add, so
+//! results are bit-identical to a build without the `simd` feature.
+
+#![allow(clippy::needless_range_loop)]
+
+use std::ops::{Add, Div, Mul, Sub};
+
+use crate::{matrix::Matrix, vector::Vector};
+
+#[cfg(target_arch = "x86_64")]
+mod arch {
+    use std::arch::x86_64::*;
+
+    #[derive(Clone, Copy)]
+    pub struct F32x4(__m128);
+
+    impl F32x4 {
+        #[inline(always)]
+        pub fn load(data: [f32; 4]) -> Self {
+            Self(unsafe { _mm_loadu_ps(data.as_ptr()) })
+        }
+
+        #[inline(always)]
+        pub fn store(self) -> [f32; 4] {
+            let mut data = [0.0; 4];
+            unsafe { _mm_storeu_ps(data.as_mut_ptr(), self.0) };
+            data
+        }
+
+        #[inline(always)]
+        pub fn splat(value: f32) -> Self {
+            Self(unsafe { _mm_set1_ps(value) })
+        }
+
+        #[inline(always)]
+        pub fn add(self, rhs: Self) -> Self {
+            Self(unsafe { _mm_add_ps(self.0, rhs.0) })
+        }
+
+        #[inline(always)]
+        pub fn sub(self, rhs: Self) -> Self {
+            Self(unsafe { _mm_sub_ps(self.0, rhs.0) })
+        }
+
+        #[inline(always)]
+        pub fn mul(self, rhs: Self) -> Self {
+            Self(unsafe { _mm_mul_ps(self.0, rhs.0) })
+        }
+
+        #[inline(always)]
+        pub fn div(self, rhs: Self) -> Self {
+            Self(unsafe { _mm_div_ps(self.0, rhs.0) })
+        }
+        #[inline(always)]
+        pub fn transpose([r0, r1, r2, r3]: [Self; 4]) -> [Self; 4] {
+            unsafe {
+                let t0 = _mm_unpacklo_ps(r0.0, r1.0);
+                let t1 = _mm_unpacklo_ps(r2.0, r3.0);
+                let t2 = _mm_unpackhi_ps(r0.0, r1.0);
+                let t3 = _mm_unpackhi_ps(r2.0, r3.0);
+                [
+                    Self(_mm_movelh_ps(t0, t1)),
+                    Self(_mm_movehl_ps(t1, t0)),
+                    Self(_mm_movelh_ps(t2, t3)),
+                    Self(_mm_movehl_ps(t3, t2)),
+                ]
+            }
+        }
+    }
+}
+
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+mod arch {
+    use std::arch::wasm32::*;
+
+    #[derive(Clone, Copy)]
+    pub struct F32x4(v128);
+
+    impl F32x4 {
+        #[inline(always)]
+        pub fn load(data: [f32; 4]) -> Self {
+            Self(f32x4(data[0], data[1], data[2], data[3]))
+        }
+
+        #[inline(always)]
+        pub fn store(self) -> [f32; 4] {
+            [
+                f32x4_extract_lane::<0>(self.0),
+                f32x4_extract_lane::<1>(self.0),
+                f32x4_extract_lane::<2>(self.0),
+                f32x4_extract_lane::<3>(self.0),
+            ]
+        }
+
+        #[inline(always)]
+        pub fn splat(value: f32) -> Self {
+            Self(f32x4_splat(value))
+        }
+
+        #[inline(always)]
+        pub fn add(self, rhs: Self) -> Self {
+            Self(f32x4_add(self.0, rhs.0))
+        }
+
+        #[inline(always)]
+        pub fn sub(self, rhs: Self) -> Self {
+            Self(f32x4_sub(self.0, rhs.0))
+        }
+
+        #[inline(always)]
+        pub fn mul(self, rhs: Self) -> Self {
+            Self(f32x4_mul(self.0, rhs.0))
+        }
+
+        #[inline(always)]
+        pub fn div(self, rhs: Self) -> Self {
+            Self(f32x4_div(self.0, rhs.0))
+        }
+        #[inline(always)]
+        pub fn transpose([r0, r1, r2, r3]: [Self; 4]) -> [Self; 4] {
+            let t0 = i32x4_shuffle::<0, 4, 1, 5>(r0.0, r1.0);
+            let t1 = i32x4_shuffle::<0, 4, 1, 5>(r2.0, r3.0);
+            let t2 = i32x4_shuffle::<2, 6, 3, 7>(r0.0, r1.0);
+            let t3 = i32x4_shuffle::<2, 6, 3, 7>(r2.0, r3.0);
+            [
+                Self(i32x4_shuffle::<0, 1, 4, 5>(t0, t1)),
+                Self(i32x4_shuffle::<2, 3, 6, 7>(t0, t1)),
+                Self(i32x4_shuffle::<0, 1, 4, 5>(t2, t3)),
+                Self(i32x4_shuffle::<2, 3, 6, 7>(t2, t3)),
+            ]
+        }
+    }
+}
+
+#[cfg(not(any(
+    target_arch = "x86_64",
+    all(target_arch = "wasm32", target_feature = "simd128")
+)))]
+mod arch {
+    #[derive(Clone, Copy)]
+    pub struct F32x4([f32; 4]);
+
+    impl F32x4 {
+        fn zip(self, rhs: Self, f: impl Fn(f32, f32) -> f32) -> Self {
+            let mut data = self.0;
+            for i in 0..4 {
+                data[i] = f(data[i], rhs.0[i]);
+            }
+            Self(data)
+        }
+
+        pub fn load(data: [f32; 4]) -> Self {
+            Self(data)
+        }
+
+        pub fn store(self) -> [f32; 4] {
+            self.0
+        }
+
+        pub fn splat(value: f32) -> Self {
+            Self([value; 4])
+        }
+
+        pub fn add(self, rhs: Self) -> Self {
+            self.zip(rhs, |a, b| a + b)
+        }
+
+        pub fn sub(self, rhs: Self) -> Self {
+            self.zip(rhs, |a, b| a - b)
+        }
+
+        pub fn mul(self, rhs: Self) -> Self {
+            self.zip(rhs, |a, b| a * b)
+        }
+
+        pub fn div(self, rhs: Self) -> Self {
+            self.zip(rhs, |a, b| a / b)
+        }
+        pub fn transpose(rows: [Self; 4]) -> [Self; 4] {
+            let mut columns = [Self([0.0; 4]); 4];
+            for row in 0..4 {
+                for col in 0..4 {
+                    columns[col].0[row] = rows[row].0[col];
+                }
+            }
+            columns
+        }
+    }
+}
+
+use arch::F32x4;
+
+#[inline]
+fn lanes(vector: Vector<f32, 4>) -> F32x4 {
+    F32x4::load(*vector)
+}
+
+impl Add for Vector<f32, 4> {
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        Vector::new(lanes(self).add(lanes(rhs)).store())
+    }
+}
+
+impl Sub for Vector<f32, 4> {
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Vector::new(lanes(self).sub(lanes(rhs)).store())
+    }
+}
+
+impl Mul<f32> for Vector<f32, 4> {
+    #[inline]
+    fn mul(self, rhs: f32) -> Self::Output {
+        Vector::new(lanes(self).mul(F32x4::splat(rhs)).store())
+    }
+}
+
+impl Div<f32> for Vector<f32, 4> {
+    #[inline]
+    fn div(self, rhs: f32) -> Self::Output {
+        Vector::new(lanes(self).div(F32x4::splat(rhs)).store())
+    }
+}
+
+impl Mul for Vector<f32, 4> {
+    #[inline]
+    fn mul(self, rhs: Self) -> Self::Output {
+        Vector::new(lanes(self).mul(lanes(rhs)).store())
+    }
+}
+
+impl Div for Vector<f32, 4> {
+    #[inline]
+    fn div(self, rhs: Self) -> Self::Output {
+        Vector::new(lanes(self).div(lanes(rhs)).store())
+    }
+}
+
+//each output row is the rows of rhs weighted by a row of self
+impl Mul for Matrix<f32, 4, 4> {
+    #[inline]
+    fn mul(self, rhs: Self) -> Self::Output {
+        let rows = rhs.map(lanes);
+        Matrix::new(self.map(|row| {
+            let mut cell = F32x4::splat(0.0);
+            for i in 0..4 {
+                cell = cell.add(F32x4::splat(row[i]).mul(rows[i]));
+            }
+            Vector::new(cell.store())
+        }))
+    }
+}
+
+//the output is the columns of self weighted by the components of rhs
+impl Mul<Vector<f32, 4>> for Matrix<f32, 4, 4> {
+    #[inline]
+    fn mul(self, rhs: Vector<f32, 4>) -> Self::Output {
+        let columns = F32x4::transpose(self.map(lanes));
+        let mut cell = F32x4::splat(0.0);
+        for i in 0..4 {
+            cell = cell.add(columns[i].mul(F32x4::splat(rhs[i])));
+        }
+        Vector::new(cell.store())
+    }
+}
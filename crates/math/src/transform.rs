@@ -0,0 +1,185 @@
+#![allow(clippy::needless_range_loop)]
+
+use std::ops::Mul;
+
+use crate::{matrix::Matrix, quaternion::Quaternion, vector::Vector, Float, Numeric};
+
+//applies scale, then rotation, then translation
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Transform<T>
+where
+    T: Numeric,
+{
+    pub translation: Vector<T, 3>,
+    pub rotation: Quaternion<T>,
+    pub scale: Vector<T, 3>,
+}
+
+impl<T> Transform<T>
+where
+    T: Numeric,
+{
+    pub fn identity() -> Self {
+        Self {
+            translation: Vector::default(),
+            rotation: Quaternion::identity(),
+            scale: Vector::new([T::one(); 3]),
+        }
+    }
+
+    pub fn from_translation(translation: Vector<T, 3>) -> Self {
+        Self {
+            translation,
+            ..Self::identity()
+        }
+    }
+
+    pub fn from_rotation(rotation: Quaternion<T>) -> Self {
+        Self {
+            rotation,
+            ..Self::identity()
+        }
+    }
+
+    pub fn from_scale(scale: Vector<T, 3>) -> Self {
+        Self {
+            scale,
+            ..Self::identity()
+        }
+    }
+}
+
+impl<T> Default for Transform<T>
+where
+    T: Numeric,
+{
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl<T> Transform<T>
+where
+    T: Float,
+{
+    pub fn transform_point(self, point: Vector<T, 3>) -> Vector<T, 3> {
+        self.translation + self.transform_vector(point)
+    }
+
+    //ignores translation
+    pub fn transform_vector(self, vector: Vector<T, 3>) -> Vector<T, 3> {
+        self.rotation * (self.scale * vector)
+    }
+
+    //exact when scale is uniform
+    pub fn inverse(self) -> Self {
+        let rotation = self.rotation.inverse();
+        let scale = Vector::new([T::one(); 3]) / self.scale;
+        let translation = scale * (rotation * -self.translation);
+        Self {
+            translation,
+            rotation,
+            scale,
+        }
+    }
+
+    pub fn lerp(self, other: Self, t: T) -> Self {
+        Self {
+            translation: self.translation.lerp(other.translation, t),
+            rotation: self.rotation.slerp(other.rotation, t),
+            scale: self.scale.lerp(other.scale, t),
+        }
+    }
+
+    pub fn to_matrix(self) -> Matrix<T, 4, 4> {
+        let rotation = self.rotation.to_matrix3();
+        let mut matrix = Matrix::<T, 4, 4>::identity();
+        for row in 0..3 {
+            for col in 0..3 {
+                matrix[row][col] = rotation[row][col] * self.scale[col];
+            }
+            matrix[row][3] = self.translation[row];
+        }
+        matrix
+    }
+
+    //splits an affine matrix without shear into translation, rotation and scale
+    pub fn from_matrix(matrix: Matrix<T, 4, 4>) -> Self {
+        let mut linear = Matrix::<T, 3, 3>::default();
+        for row in 0..3 {
+            for col in 0..3 {
+                linear[row][col] = matrix[row][col];
+            }
+        }
+
+        let mut scale = Vector::<T, 3>::default();
+        for col in 0..3 {
+            let column = Vector::new([linear[0][col], linear[1][col], linear[2][col]]);
+            scale[col] = column.magnitude();
+        }
+
+        //a reflection is folded into the x scale
+        if linear.determinant() < T::zero() {
+            scale[0] = -scale[0];
+        }
+
+        for row in 0..3 {
+            for col in 0..3 {
+                linear[row][col] /= scale[col];
+            }
+        }
+
+        Self {
+            translation: Vector::new([matrix[0][3], matrix[1][3], matrix[2][3]]),
+            rotation: Quaternion::from_matrix3(linear),
+            scale,
+        }
+    }
+}
+
+//applies rhs first and then self. exact when self's scale is uniform, as
+//a non-uniform scale applied after rhs's rotation is a shear, which a
+//transform cannot hold. the scales are then just multiplied per axis
+impl<T> Mul for Transform<T>
+where
+    T: Float,
+{
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self {
+            translation: self.transform_point(rhs.translation),
+            rotation: self.rotation * rhs.rotation,
+            scale: self.scale * rhs.scale,
+        }
+    }
+}
+
+impl<T> Mul<Vector<T, 3>> for Transform<T>
+where
+    T: Float,
+{
+    type Output = Vector<T, 3>;
+
+    fn mul(self, rhs: Vector<T, 3>) -> Self::Output {
+        self.transform_point(rhs)
+    }
+}
+
+impl<T> From<Transform<T>> for Matrix<T, 4, 4>
+where
+    T: Float,
+{
+    fn from(transform: Transform<T>) -> Self {
+        transform.to_matrix()
+    }
+}
+
+impl<T> From<Matrix<T, 4, 4>> for Transform<T>
+where
+    T: Float,
+{
+    fn from(matrix: Matrix<T, 4, 4>) -> Self {
+        Self::from_matrix(matrix)
+    }
+}
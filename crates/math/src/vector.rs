@@ -1,9 +1,11 @@
 #![allow(clippy::needless_range_loop)]
 
 use std::convert::FloatToInt;
-use std::ops::{Add, AddAssign, Deref, DerefMut, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
+use std::ops::{
+    Add, AddAssign, Deref, DerefMut, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign,
+};
 
-use crate::Numeric;
+use crate::{angle::Radians, Float, Numeric};
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 #[repr(transparent)]
@@ -27,18 +29,40 @@ impl<T, const N: usize> Vector<T, N>
 where
     T: Numeric,
 {
-    pub fn resize<const M: usize>(self) -> Vector<T, M> {
+    //pads with fill, failing to compile if M is less than N
+    pub fn extend<const M: usize>(self, fill: T) -> Vector<T, M> {
+        const { assert!(M >= N, "extend cannot shrink a vector") };
+
+        let mut data = [fill; M];
+        data[..N].copy_from_slice(&self.data);
+
+        Vector::<T, M> { data }
+    }
+
+    //keeps the first M components, failing to compile if M is greater than N
+    pub fn truncate<const M: usize>(self) -> Vector<T, M> {
+        const { assert!(M <= N, "truncate cannot grow a vector") };
+
         let mut data = [T::default(); M];
+        data.copy_from_slice(&self.data[..M]);
 
-        for i in 0..M {
-            if i > N {
-                break;
-            }
+        Vector::<T, M> { data }
+    }
 
-            data[i] = self[i];
+    fn map(self, f: impl Fn(T) -> T) -> Self {
+        let mut data = self.data;
+        for i in 0..N {
+            data[i] = f(data[i]);
         }
+        Self { data }
+    }
 
-        Vector::<T, M> { data }
+    fn zip(self, rhs: Self, f: impl Fn(T, T) -> T) -> Self {
+        let mut data = self.data;
+        for i in 0..N {
+            data[i] = f(data[i], rhs.data[i]);
+        }
+        Self { data }
     }
 
     pub fn cast<U>(self) -> Vector<U, N>
@@ -97,37 +121,50 @@ where
 impl<T, const N: usize> Vector<T, N>
 where
     T: Numeric,
-    T: Add<Output = T>,
-    T: Mul<Output = T>,
 {
     pub fn dot(self, rhs: Self) -> T {
-        let mut dot = T::default();
+        let mut dot = T::zero();
         for i in 0..N {
             dot += self.data[i] * rhs.data[i];
         }
         dot
     }
-}
 
-impl<const N: usize> Vector<f32, N> {
-    pub fn distance(&self, other: &Self) -> f32 {
-        self.distance_squared(other).sqrt()
+    pub fn magnitude_squared(&self) -> T {
+        self.dot(*self)
     }
 
-    pub fn distance_squared(&self, other: &Self) -> f32 {
-        let mut accum = 0.0;
-        for i in 0..N {
-            accum += (self.data[i] - other.data[i]) * (self.data[i] - other.data[i])
-        }
-        accum
+    pub fn distance_squared(&self, other: &Self) -> T {
+        (*self - *other).magnitude_squared()
     }
 
-    pub fn magnitude(&self) -> f32 {
-        let mut accum = 0.0;
-        for i in 0..N {
-            accum += self.data[i] * self.data[i];
-        }
-        accum.sqrt()
+    pub fn min(self, rhs: Self) -> Self {
+        self.zip(rhs, |a, b| if b < a { b } else { a })
+    }
+
+    pub fn max(self, rhs: Self) -> Self {
+        self.zip(rhs, |a, b| if b > a { b } else { a })
+    }
+
+    pub fn clamp(self, min: Self, max: Self) -> Self {
+        self.max(min).min(max)
+    }
+
+    pub fn abs(self) -> Self {
+        self.map(|a| if a < T::zero() { T::zero() - a } else { a })
+    }
+}
+
+impl<T, const N: usize> Vector<T, N>
+where
+    T: Float,
+{
+    pub fn distance(&self, other: &Self) -> T {
+        self.distance_squared(other).sqrt()
+    }
+
+    pub fn magnitude(&self) -> T {
+        self.magnitude_squared().sqrt()
     }
 
     pub fn normalize(self) -> Self {
@@ -135,51 +172,41 @@ impl<const N: usize> Vector<f32, N> {
         self / magnitude
     }
 
-    pub fn castf<U>(self) -> Vector<U, N>
-    where
-        f32: FloatToInt<U>,
-        U: Numeric + Default,
-    {
-        let mut data = [U::default(); N];
+    pub fn floor(self) -> Self {
+        self.map(T::floor)
+    }
 
-        for i in 0..N {
-            data[i] = unsafe { self[i].to_int_unchecked() };
-        }
+    pub fn ceil(self) -> Self {
+        self.map(T::ceil)
+    }
 
-        Vector::<U, N> { data }
+    pub fn lerp(self, rhs: Self, t: T) -> Self {
+        self.zip(rhs, |a, b| a.lerp(b, t))
     }
-}
 
-impl<const N: usize> Vector<f64, N> {
-    pub fn distance(&self, other: &Self) -> f64 {
-        self.distance_squared(other).sqrt()
+    //mirrors self about the plane with the given normal, which must be normalized
+    pub fn reflect(self, normal: Self) -> Self {
+        self - normal * (self.dot(normal) * (T::one() + T::one()))
     }
 
-    pub fn distance_squared(&self, other: &Self) -> f64 {
-        let mut accum = 0.0;
-        for i in 0..N {
-            accum += (self.data[i] - other.data[i]) * (self.data[i] - other.data[i])
-        }
-        accum
+    pub fn project(self, onto: Self) -> Self {
+        onto * (self.dot(onto) / onto.dot(onto))
     }
 
-    pub fn magnitude(&self) -> f64 {
-        let mut accum = 0.0;
-        for i in 0..N {
-            accum += self.data[i] * self.data[i];
-        }
-        accum.sqrt()
+    //between 0 and pi
+    pub fn angle_between(self, rhs: Self) -> Radians<T> {
+        let cos = self.dot(rhs) / (self.magnitude() * rhs.magnitude());
+        Radians(cos.clamp(-T::one(), T::one()).acos())
     }
 
-    pub fn normalize(self) -> Self {
-        let magnitude = self.magnitude();
-        self / magnitude
+    pub fn is_finite(&self) -> bool {
+        self.data.iter().all(|a| a.is_finite())
     }
 
     pub fn castf<U>(self) -> Vector<U, N>
     where
-        f64: FloatToInt<U>,
-        U: Numeric + Default,
+        T: FloatToInt<U>,
+        U: Numeric,
     {
         let mut data = [U::default(); N];
 
@@ -191,12 +218,48 @@ impl<const N: usize> Vector<f64, N> {
     }
 }
 
+macro_rules! accessors {
+    ($n:literal: $($name:ident => $index:literal),*) => {
+        impl<T> Vector<T, $n>
+        where
+            T: Numeric,
+        {
+            $(
+                pub fn $name(&self) -> T {
+                    self.data[$index]
+                }
+            )*
+        }
+    };
+}
+
+accessors!(2: x => 0, y => 1);
+accessors!(3: x => 0, y => 1, z => 2);
+accessors!(4: x => 0, y => 1, z => 2, w => 3);
+
+macro_rules! swizzles {
+    ($n:literal: $($name:ident => [$($index:literal),*]),*) => {
+        impl<T> Vector<T, $n>
+        where
+            T: Numeric,
+        {
+            $(
+                pub fn $name(&self) -> Vector<T, { [$($index),*].len() }> {
+                    Vector::new([$(self.data[$index]),*])
+                }
+            )*
+        }
+    };
+}
+
+swizzles!(2: xy => [0, 1], yx => [1, 0]);
+swizzles!(3: xy => [0, 1], yx => [1, 0], xz => [0, 2], yz => [1, 2], xyz => [0, 1, 2], zyx => [2, 1, 0]);
+swizzles!(4: xy => [0, 1], yx => [1, 0], xz => [0, 2], yz => [1, 2], xyz => [0, 1, 2], zyx => [2, 1, 0], wzyx => [3, 2, 1, 0]);
+
 //TODO seven dimension cross product lol
 impl<T> Vector<T, 3>
 where
     T: Numeric,
-    T: Mul<Output = T>,
-    T: Sub<Output = T>,
     [T; 3]: Default,
 {
     pub fn cross(self, rhs: Self) -> Self {
@@ -211,11 +274,10 @@ where
 impl<T, const N: usize> Add for Vector<T, N>
 where
     T: Numeric,
-    T: Add<Output = T>,
 {
     type Output = Self;
 
-    fn add(self, rhs: Self) -> Self::Output {
+    default fn add(self, rhs: Self) -> Self::Output {
         let mut data = [T::default(); N];
         for i in 0..N {
             data[i] = self.data[i] + rhs.data[i];
@@ -227,7 +289,6 @@ where
 impl<T, const N: usize> AddAssign for Vector<T, N>
 where
     T: Numeric,
-    T: Add<Output = T>,
 {
     fn add_assign(&mut self, rhs: Self) {
         *self = *self + rhs;
@@ -237,11 +298,10 @@ where
 impl<T, const N: usize> Sub for Vector<T, N>
 where
     T: Numeric,
-    T: Sub<Output = T>,
 {
     type Output = Self;
 
-    fn sub(self, rhs: Self) -> Self::Output {
+    default fn sub(self, rhs: Self) -> Self::Output {
         let mut data = [T::default(); N];
         for i in 0..N {
             data[i] = self.data[i] - rhs.data[i];
@@ -253,7 +313,6 @@ where
 impl<T, const N: usize> SubAssign for Vector<T, N>
 where
     T: Numeric,
-    T: Sub<Output = T>,
 {
     fn sub_assign(&mut self, rhs: Self) {
         *self = *self - rhs;
@@ -263,13 +322,12 @@ where
 impl<T, const N: usize> Mul<T> for Vector<T, N>
 where
     T: Numeric,
-    T: Mul<Output = T>,
 {
     type Output = Self;
-    fn mul(self, rhs: T) -> Self::Output {
+    default fn mul(self, rhs: T) -> Self::Output {
         let mut data = self.data;
         for i in 0..N {
-            data[i] = data[i] * rhs;
+            data[i] *= rhs;
         }
         Self { data }
     }
@@ -278,7 +336,6 @@ where
 impl<T, const N: usize> MulAssign<T> for Vector<T, N>
 where
     T: Numeric,
-    T: Mul<Output = T>,
 {
     fn mul_assign(&mut self, rhs: T) {
         *self = *self * rhs;
@@ -288,13 +345,12 @@ where
 impl<T, const N: usize> Div<T> for Vector<T, N>
 where
     T: Numeric,
-    T: Div<Output = T>,
 {
     type Output = Self;
-    fn div(self, rhs: T) -> Self::Output {
+    default fn div(self, rhs: T) -> Self::Output {
         let mut data = self.data;
         for i in 0..N {
-            data[i] = data[i] / rhs;
+            data[i] /= rhs;
         }
         Self { data }
     }
@@ -303,9 +359,60 @@ where
 impl<T, const N: usize> DivAssign<T> for Vector<T, N>
 where
     T: Numeric,
-    T: Div<Output = T>,
 {
     fn div_assign(&mut self, rhs: T) {
         *self = *self / rhs;
     }
 }
+
+impl<T, const N: usize> Mul for Vector<T, N>
+where
+    T: Numeric,
+{
+    type Output = Self;
+
+    default fn mul(self, rhs: Self) -> Self::Output {
+        self.zip(rhs, |a, b| a * b)
+    }
+}
+
+impl<T, const N: usize> MulAssign for Vector<T, N>
+where
+    T: Numeric,
+{
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<T, const N: usize> Div for Vector<T, N>
+where
+    T: Numeric,
+{
+    type Output = Self;
+
+    default fn div(self, rhs: Self) -> Self::Output {
+        self.zip(rhs, |a, b| a / b)
+    }
+}
+
+impl<T, const N: usize> DivAssign for Vector<T, N>
+where
+    T: Numeric,
+{
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+impl<T, const N: usize> Neg for Vector<T, N>
+where
+    T: Numeric,
+    T: Neg<Output = T>,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        self.map(|a| -a)
+    }
+}
@@ -0,0 +1,107 @@
+use math::prelude::*;
+use std::f64::consts::{FRAC_PI_2, PI};
+
+const EPSILON: f64 = 1e-9;
+
+fn approx(a: Matrix<f64, 3, 3>, b: Matrix<f64, 3, 3>) -> bool {
+    (0..3).all(|row| a[row].distance(&b[row]) < EPSILON)
+}
+
+fn radians(angles: [f64; 3]) -> [Radians<f64>; 3] {
+    angles.map(Radians)
+}
+
+#[test]
+fn units_convert_and_combine() {
+    let right = Radians::from(Degrees(90.0_f64));
+    assert!((right.0 - FRAC_PI_2).abs() < EPSILON);
+    assert!((Degrees::from(Radians(PI)).0 - 180.0).abs() < EPSILON);
+
+    assert_eq!(Degrees(30.0) + Degrees(15.0) * 2.0, Degrees(60.0));
+    assert_eq!(-(Degrees(90.0) - Degrees(30.0)), Degrees(-60.0));
+    assert_eq!(Degrees(90.0) / Degrees(30.0), 3.0);
+
+    let (sin, cos) = right.sin_cos();
+    assert!((sin - 1.0).abs() < EPSILON && cos.abs() < EPSILON);
+}
+
+#[test]
+fn wrap_keeps_angles_within_half_turn() {
+    assert_eq!(Degrees(370.0_f64).wrap(), Degrees(10.0));
+    assert_eq!(Degrees(-190.0_f64).wrap(), Degrees(170.0));
+    assert_eq!(Degrees(-180.0_f64).wrap(), Degrees(180.0));
+    assert_eq!(Degrees(180.0_f64).wrap(), Degrees(180.0));
+    assert!((Radians(3.0 * PI).wrap().0 - PI).abs() < EPSILON);
+}
+
+#[test]
+fn xyz_matches_from_euler() {
+    let euler = Quaternion::<f64>::from_euler(Radians(0.3), Radians(-1.2), Radians(2.0));
+    let rot = Quaternion::from_euler_rot(EulerRot::XYZ, radians([0.3, -1.2, 2.0]));
+
+    assert!((euler.dot(rot).abs() - 1.0).abs() < EPSILON);
+
+    //world x first, so it is the rightmost factor
+    let axes = Matrix::<f64, 4, 4>::rotation_z(Radians(2.0))
+        * Matrix::rotation_y(Radians(-1.2))
+        * Matrix::rotation_x(Radians(0.3));
+    let euler = euler.to_matrix4();
+    for row in 0..4 {
+        assert!(axes[row].distance(&euler[row]) < EPSILON);
+    }
+
+    //angles in degrees convert on the way in
+    let degrees = Quaternion::<f64>::from_euler(Degrees(90.0), Radians(0.0), Degrees(-45.0));
+    let expected =
+        Quaternion::from_euler_rot(EulerRot::XYZ, radians([FRAC_PI_2, 0.0, -FRAC_PI_2 / 2.0]));
+    assert!((degrees.dot(expected).abs() - 1.0).abs() < EPSILON);
+
+    //about world x by 90, then world z by 90, takes y to x
+    let rotation = Matrix::from_euler_rot(EulerRot::XZY, radians([FRAC_PI_2, 0.0, FRAC_PI_2]));
+    let rotated = rotation * Vector::new([0.0, 1.0, 0.0]);
+    assert!(rotated.distance(&Vector::new([0.0, 0.0, 1.0])) < EPSILON);
+}
+
+#[test]
+fn orders_round_trip() {
+    let angles = [[0.3, -1.2, 2.0], [-2.9, 0.7, 0.1], [1.0, 1.5, -3.0]];
+
+    for order in EulerRot::ALL {
+        for angles in angles {
+            //the middle axis has to start in range to come back unchanged
+            let middle = order.axes()[1];
+            let mut angles = angles;
+            angles[middle] = angles[middle].clamp(-1.4, 1.4);
+
+            let matrix = Matrix::from_euler_rot(order, radians(angles));
+            let extracted = matrix.to_euler_rot(order).map(|angle| angle.0);
+            for axis in 0..3 {
+                assert!((extracted[axis] - angles[axis]).abs() < 1e-9, "{order:?}");
+            }
+
+            let quaternion = Quaternion::from_euler_rot(order, radians(angles));
+            assert!(approx(quaternion.to_matrix3(), matrix));
+            let extracted = quaternion.to_euler_rot(order);
+            assert!(approx(Matrix::from_euler_rot(order, extracted), matrix));
+        }
+    }
+}
+
+#[test]
+fn gimbal_lock_rebuilds_rotation() {
+    for order in EulerRot::ALL {
+        for middle in [FRAC_PI_2, -FRAC_PI_2] {
+            let mut angles = [0.4, -0.9, 1.3];
+            angles[order.axes()[1]] = middle;
+
+            let matrix = Matrix::from_euler_rot(order, radians(angles));
+            let extracted = matrix.to_euler_rot(order);
+
+            assert_eq!(extracted[order.axes()[0]], Radians(0.0));
+            assert!(
+                approx(Matrix::from_euler_rot(order, extracted), matrix),
+                "{order:?}"
+            );
+        }
+    }
+}
@@ -0,0 +1,117 @@
+use math::prelude::*;
+
+const EPSILON: f32 = 1e-4;
+
+fn approx(a: [f32; 4], b: [f32; 4]) -> bool {
+    a.iter().zip(b).all(|(a, b)| (a - b).abs() < EPSILON)
+}
+
+#[test]
+fn srgb_round_trips_every_byte() {
+    for byte in 0..=255_u8 {
+        let srgb = Srgba::from_u8_array([byte, byte, byte, byte]);
+        let linear = LinearRgba::from(srgb);
+
+        assert_eq!(linear.alpha, srgb.alpha);
+        assert_eq!(Srgba::from(linear).to_u8_array(), [byte; 4]);
+    }
+
+    let half = LinearRgba::from(Srgba::rgb(0.5, 0.5, 0.5));
+    assert!(approx(half.to_array(), [0.21404, 0.21404, 0.21404, 1.0]));
+}
+
+#[test]
+fn packs_as_rgba() {
+    let color = Srgba::from_u32(0xff8000c0);
+
+    assert_eq!(color.to_u8_array(), [0xff, 0x80, 0x00, 0xc0]);
+    assert_eq!(color.to_u32(), 0xff8000c0);
+    assert_eq!(
+        Srgba::new(2.0, -1.0, 0.0, 1.0).to_u8_array(),
+        [255, 0, 0, 255]
+    );
+}
+
+#[test]
+fn hsl_matches_reference_colors() {
+    for (hsla, srgba) in [
+        (Hsla::new(0.0, 1.0, 0.5, 1.0), Srgba::RED),
+        (Hsla::new(120.0, 1.0, 0.25, 1.0), Srgba::rgb(0.0, 0.5, 0.0)),
+        (
+            Hsla::new(240.0, 1.0, 0.5, 0.5),
+            Srgba::new(0.0, 0.0, 1.0, 0.5),
+        ),
+        (Hsla::new(0.0, 0.0, 0.75, 1.0), Srgba::rgb(0.75, 0.75, 0.75)),
+        (
+            Hsla::new(300.0, 0.5, 0.5, 1.0),
+            Srgba::rgb(0.75, 0.25, 0.75),
+        ),
+    ] {
+        assert!(approx(Srgba::from(hsla).to_array(), srgba.to_array()));
+
+        let round_trip = Hsla::from(srgba);
+        assert!(approx(
+            [
+                round_trip.hue / 360.0,
+                round_trip.saturation,
+                round_trip.lightness,
+                round_trip.alpha
+            ],
+            [
+                hsla.hue / 360.0,
+                hsla.saturation,
+                hsla.lightness,
+                hsla.alpha
+            ]
+        ));
+    }
+}
+
+#[test]
+fn oklab_matches_reference_colors() {
+    let white = Oklab::from(LinearRgba::WHITE);
+    assert!(approx(
+        [white.lightness, white.a, white.b, white.alpha],
+        [1.0, 0.0, 0.0, 1.0]
+    ));
+
+    let red = Oklab::from(Srgba::RED);
+    assert!(approx(
+        [red.lightness, red.a, red.b, red.alpha],
+        [0.62796, 0.22486, 0.12585, 1.0]
+    ));
+
+    let color = LinearRgba::new(0.2, 0.7, 0.4, 0.3);
+    assert!(approx(
+        LinearRgba::from(Oklab::from(color)).to_array(),
+        color.to_array()
+    ));
+}
+
+#[test]
+fn mixing_and_blending() {
+    let mixed = LinearRgba::BLACK.mix(LinearRgba::WHITE, 0.25);
+    assert!(approx(mixed.to_array(), [0.25, 0.25, 0.25, 1.0]));
+
+    //hue takes the short way from 350 to 30 through 0
+    let hue = Hsla::new(350.0, 1.0, 0.5, 1.0).mix(Hsla::new(30.0, 1.0, 0.5, 1.0), 0.25);
+    assert!((hue.hue - 0.0).abs() < EPSILON);
+
+    let half_red = LinearRgba::new(1.0, 0.0, 0.0, 0.5);
+    assert!(approx(
+        half_red.blend(LinearRgba::BLUE).to_array(),
+        [0.5, 0.0, 0.5, 1.0]
+    ));
+    assert!(approx(
+        LinearRgba::RED.blend(LinearRgba::BLUE).to_array(),
+        LinearRgba::RED.to_array()
+    ));
+    assert!(approx(
+        half_red.blend(LinearRgba::TRANSPARENT).to_array(),
+        half_red.to_array()
+    ));
+    assert_eq!(
+        LinearRgba::TRANSPARENT.blend(LinearRgba::TRANSPARENT),
+        LinearRgba::TRANSPARENT
+    );
+}
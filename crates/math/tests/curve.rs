@@ -0,0 +1,170 @@
+use math::prelude::*;
+
+const EPSILON: f64 = 1e-6;
+
+fn approx(a: Vector<f64, 2>, b: Vector<f64, 2>, epsilon: f64) -> bool {
+    a.distance(&b) < epsilon
+}
+
+fn points(data: &[[f64; 2]]) -> Vec<Vector<f64, 2>> {
+    data.iter().copied().map(Vector::new).collect()
+}
+
+//compares both derivatives against central differences
+fn check_derivatives(curve: &impl Curve<f64, 2>) {
+    let h = 1e-5;
+    for i in 1..20 {
+        let t = i as f64 / 20.0;
+
+        let derivative = (curve.evaluate(t + h) - curve.evaluate(t - h)) / (2.0 * h);
+        assert!(approx(curve.derivative(t), derivative, 1e-4));
+
+        let second = (curve.derivative(t + h) - curve.derivative(t - h)) / (2.0 * h);
+        assert!(approx(curve.second_derivative(t), second, 1e-3));
+    }
+}
+
+#[test]
+fn bezier_hits_endpoints() {
+    let quadratic = QuadraticBezier::new([
+        Vector::new([0.0, 0.0]),
+        Vector::new([1.0, 2.0]),
+        Vector::new([2.0, 0.0]),
+    ]);
+    let cubic = CubicBezier::new([
+        Vector::new([0.0, 0.0]),
+        Vector::new([0.0, 1.0]),
+        Vector::new([1.0, 1.0]),
+        Vector::new([1.0, 0.0]),
+    ]);
+
+    assert!(approx(
+        quadratic.evaluate(0.0),
+        quadratic.points[0],
+        EPSILON
+    ));
+    assert!(approx(
+        quadratic.evaluate(0.5),
+        Vector::new([1.0, 1.0]),
+        EPSILON
+    ));
+    assert!(approx(
+        quadratic.evaluate(1.0),
+        quadratic.points[2],
+        EPSILON
+    ));
+    assert!(approx(cubic.evaluate(0.0), cubic.points[0], EPSILON));
+    assert!(approx(
+        cubic.evaluate(0.5),
+        Vector::new([0.5, 0.75]),
+        EPSILON
+    ));
+    assert!(approx(cubic.evaluate(1.0), cubic.points[3], EPSILON));
+    assert!(approx(
+        cubic.derivative(0.0),
+        Vector::new([0.0, 3.0]),
+        EPSILON
+    ));
+
+    check_derivatives(&quadratic);
+    check_derivatives(&cubic);
+}
+
+#[test]
+fn hermite_matches_endpoints_and_tangents() {
+    let hermite = Hermite::new(
+        Vector::new([0.0, 0.0]),
+        Vector::new([1.0, 2.0]),
+        Vector::new([3.0, 1.0]),
+        Vector::new([0.0, -1.0]),
+    );
+
+    assert!(approx(hermite.evaluate(0.0), hermite.start, EPSILON));
+    assert!(approx(hermite.evaluate(1.0), hermite.end, EPSILON));
+    assert!(approx(
+        hermite.derivative(0.0),
+        hermite.start_tangent,
+        EPSILON
+    ));
+    assert!(approx(
+        hermite.derivative(1.0),
+        hermite.end_tangent,
+        EPSILON
+    ));
+
+    check_derivatives(&hermite);
+}
+
+#[test]
+fn catmull_rom_passes_through_inner_points() {
+    let spline = CatmullRom::new(points(&[
+        [-1.0, 0.0],
+        [0.0, 0.0],
+        [1.0, 1.0],
+        [2.0, 0.0],
+        [3.0, 2.0],
+        [4.0, 2.0],
+    ]))
+    .unwrap();
+
+    //three segments between the four inner points
+    for (i, point) in spline.points()[1..5].iter().enumerate() {
+        assert!(approx(spline.evaluate(i as f64 / 3.0), *point, EPSILON));
+    }
+
+    //the tangent at an inner point is half the chord around it
+    let tangent = (spline.points()[3] - spline.points()[1]) * 0.5 * 3.0;
+    assert!(approx(spline.derivative(1.0 / 3.0), tangent, EPSILON));
+
+    check_derivatives(&spline);
+    assert!(CatmullRom::<f64, 2>::new(points(&[[0.0, 0.0]; 3])).is_none());
+}
+
+#[test]
+fn b_spline_is_smooth_across_segments() {
+    let spline = BSpline::new(points(&[
+        [0.0, 0.0],
+        [1.0, 3.0],
+        [2.0, -1.0],
+        [3.0, 2.0],
+        [4.0, 0.0],
+    ]))
+    .unwrap();
+
+    //the start of a uniform b-spline is weighted 1:4:1 over its first points
+    let start = (spline.points()[0] + spline.points()[1] * 4.0 + spline.points()[2]) / 6.0;
+    assert!(approx(spline.evaluate(0.0), start, EPSILON));
+
+    let h = 1e-9;
+    for f in [
+        |spline: &BSpline<f64, 2>, t| spline.evaluate(t),
+        |spline: &BSpline<f64, 2>, t| spline.derivative(t),
+        |spline: &BSpline<f64, 2>, t| spline.second_derivative(t),
+    ] {
+        assert!(approx(f(&spline, 0.5 - h), f(&spline, 0.5 + h), 1e-6));
+    }
+
+    check_derivatives(&spline);
+}
+
+#[test]
+fn arc_length_moves_at_constant_speed() {
+    //control points bunched at the start make the raw parameter uneven
+    let line = CubicBezier::new([
+        Vector::new([0.0, 0.0]),
+        Vector::new([0.1, 0.0]),
+        Vector::new([0.2, 0.0]),
+        Vector::new([4.0, 0.0]),
+    ]);
+    let table = ArcLength::new(&line, 1000);
+
+    assert!((table.length() - 4.0).abs() < EPSILON);
+    assert_eq!(table.parameter(-1.0), 0.0);
+    assert_eq!(table.parameter(10.0), 1.0);
+
+    for i in 0..=10 {
+        let fraction = i as f64 / 10.0;
+        let position = line.evaluate(table.parameter_at_fraction(fraction));
+        assert!((position[0] - fraction * 4.0).abs() < 1e-3);
+    }
+}
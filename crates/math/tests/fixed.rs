@@ -0,0 +1,110 @@
+use math::prelude::*;
+use std::f64::consts::PI;
+
+fn q16(value: f64) -> Q16_16 {
+    Q16_16::from_f64(value)
+}
+
+fn q32(value: f64) -> Q32_32 {
+    Q32_32::from_f64(value)
+}
+
+#[test]
+fn arithmetic_rounds_to_nearest_step() {
+    assert_eq!((q16(1.5) + q16(2.25)).to_f64(), 3.75);
+    assert_eq!((q16(1.5) - q16(2.25)).to_f64(), -0.75);
+    assert_eq!((q16(1.5) * q16(-2.5)).to_f64(), -3.75);
+    assert_eq!((q16(7.0) / q16(2.0)).to_f64(), 3.5);
+    assert_eq!((-q16(0.5)).to_f64(), -0.5);
+
+    //half a step times a half rounds up to a whole step
+    let step = Q16_16::from_bits(1);
+    assert_eq!(step * q16(0.5), step);
+    assert_eq!(Q16_16::from_bits(1) / q16(3.0), Q16_16::zero());
+
+    assert_eq!(Q16_16::from_int(-3).to_int(), -3);
+    assert_eq!(q16(-2.5).to_int(), -3);
+    assert_eq!(q16(-2.5).floor(), q16(-3.0));
+    assert_eq!(q16(-2.5).ceil(), q16(-2.0));
+    assert_eq!(q32(2.0).ceil(), q32(2.0));
+}
+
+#[test]
+fn saturating_ops_clamp() {
+    let big = Q16_16::from_int(30000);
+
+    assert_eq!(big.saturating_add(big), Q16_16::MAX);
+    assert_eq!((-big).saturating_sub(big), Q16_16::MIN);
+    assert_eq!(big.saturating_mul(big), Q16_16::MAX);
+    assert_eq!(big.saturating_mul(-big), Q16_16::MIN);
+    assert_eq!(q16(1.0).saturating_div(Q16_16::zero()), Q16_16::MAX);
+    assert_eq!(q16(-1.0).saturating_div(Q16_16::zero()), Q16_16::MIN);
+    assert_eq!(q16(6.0).saturating_div(q16(4.0)), q16(1.5));
+
+    //the operators wrap instead
+    assert_eq!(Q16_16::MAX + Q16_16::from_bits(1), Q16_16::MIN);
+    assert!(!Q16_16::INFINITY.is_finite());
+}
+
+#[test]
+fn sqrt_is_exact_to_the_step() {
+    assert_eq!(q16(16.0).sqrt(), q16(4.0));
+    assert_eq!(q32(2.25).sqrt(), q32(1.5));
+    assert_eq!(q16(-1.0).sqrt(), Q16_16::zero());
+    assert!((q32(2.0).sqrt().to_f64() - 2.0_f64.sqrt()).abs() < 1e-9);
+}
+
+#[test]
+fn trig_matches_floats() {
+    for i in -100..100 {
+        let angle = i as f64 * 0.173;
+
+        assert!((q16(angle).sin().to_f64() - angle.sin()).abs() < 1e-4);
+        assert!((q16(angle).cos().to_f64() - angle.cos()).abs() < 1e-4);
+        assert!((q32(angle).sin().to_f64() - angle.sin()).abs() < 2e-6);
+        assert!((q32(angle).cos().to_f64() - angle.cos()).abs() < 2e-6);
+
+        let (y, x) = (angle.sin() * 3.0, (angle * 0.7).cos() * 2.0);
+        assert!((q32(y).atan2(q32(x)).to_f64() - y.atan2(x)).abs() < 2e-6);
+    }
+
+    for i in -10..=10 {
+        let x = i as f64 / 10.0;
+        assert!((q32(x).asin().to_f64() - x.asin()).abs() < 2e-6);
+        assert!((q32(x).acos().to_f64() - x.acos()).abs() < 2e-6);
+    }
+
+    assert!((Q32_32::PI.to_f64() - PI).abs() < 1e-9);
+    assert_eq!(q32(-1.0).atan2(Q32_32::zero()), -Q32_32::PI / q32(2.0));
+}
+
+#[test]
+fn bits_are_platform_independent() {
+    //golden bits, which any platform must reproduce exactly
+    assert_eq!(q16(1.0).sin().to_bits(), 55147);
+    assert_eq!(q16(2.0).sqrt().to_bits(), 92681);
+    assert_eq!(q16(0.5).atan2(q16(1.5)).to_bits(), 21086);
+    assert_eq!(q32(-7.25).cos().to_bits(), 2439215188);
+}
+
+#[test]
+fn drives_vectors_and_matrices() {
+    let direction = Vector::new([q16(3.0), q16(0.0), q16(4.0)]);
+    assert_eq!(direction.magnitude(), q16(5.0));
+    for (actual, expected) in direction.normalize().iter().zip([0.6, 0.0, 0.8]) {
+        assert!((actual.to_f64() - expected).abs() < 1e-4);
+    }
+
+    let rotation = Matrix::<Q32_32, 4, 4>::rotation_z(Radians(Q32_32::PI / q32(2.0)));
+    let rotated = rotation * Vector::new([q32(1.0), q32(0.0), q32(0.0), q32(1.0)]);
+    for (actual, expected) in rotated.iter().zip([0.0, 1.0, 0.0, 1.0]) {
+        assert!((actual.to_f64() - expected).abs() < 2e-6);
+    }
+
+    let spin = Quaternion::from_axis_angle(
+        Vector::new([q32(0.0), q32(1.0), q32(0.0)]),
+        Radians(Q32_32::PI),
+    );
+    let flipped = spin * Vector::new([q32(1.0), q32(0.0), q32(0.0)]);
+    assert!((flipped[0].to_f64() + 1.0).abs() < 1e-5);
+}
@@ -0,0 +1,186 @@
+use math::prelude::*;
+use std::f32::consts::FRAC_PI_2;
+
+const EPSILON: f32 = 1e-5;
+
+fn approx(a: Vector<f32, 3>, b: Vector<f32, 3>) -> bool {
+    a.distance(&b) < EPSILON
+}
+
+fn unit_box() -> Aabb<f32, 3> {
+    Aabb::new(
+        Vector::new([-1.0, -1.0, -1.0]),
+        Vector::new([1.0, 1.0, 1.0]),
+    )
+}
+
+#[test]
+fn aabb_overlap_and_containment() {
+    let a = unit_box();
+    let b = Aabb::new(Vector::new([0.5, 0.5, 0.5]), Vector::new([2.0, 2.0, 2.0]));
+    let c = Aabb::new(Vector::new([1.5, 0.0, 0.0]), Vector::new([2.0, 1.0, 1.0]));
+
+    assert!(a.intersects_aabb(&b));
+    assert!(!a.intersects_aabb(&c));
+    assert!(a.union(c).contains_aabb(&a));
+    assert!(!a.contains_aabb(&b));
+
+    let points = [Vector::new([0.0, 1.0]), Vector::new([-2.0, 3.0])];
+    let bounds = Aabb::<f32, 2>::from_points(&points).unwrap();
+    assert_eq!(bounds.min, Vector::new([-2.0, 1.0]));
+    assert_eq!(bounds.max, Vector::new([0.0, 3.0]));
+    assert!(Aabb::<f32, 2>::from_points(&[]).is_none());
+}
+
+#[test]
+fn aabb_closest_point_clamps() {
+    let aabb = unit_box();
+
+    assert!(approx(
+        aabb.closest_point(Vector::new([3.0, 0.5, -4.0])),
+        Vector::new([1.0, 0.5, -1.0])
+    ));
+    assert_eq!(aabb.distance_squared(Vector::new([0.0, 0.0, 0.0])), 0.0);
+    assert_eq!(aabb.distance_squared(Vector::new([3.0, 0.0, 0.0])), 4.0);
+}
+
+#[test]
+fn ray_hits_aabb() {
+    let aabb = unit_box();
+
+    let ray = Ray::<f32, 3>::new(Vector::new([-5.0, 0.0, 0.0]), Vector::new([1.0, 0.0, 0.0]));
+    assert_eq!(ray.intersects_aabb(&aabb), Some(4.0));
+
+    let inside = Ray::<f32, 3>::new(Vector::new([0.0, 0.0, 0.0]), Vector::new([0.0, 1.0, 0.0]));
+    assert_eq!(inside.intersects_aabb(&aabb), Some(0.0));
+
+    let away = Ray::<f32, 3>::new(Vector::new([-5.0, 0.0, 0.0]), Vector::new([-1.0, 0.0, 0.0]));
+    assert_eq!(away.intersects_aabb(&aabb), None);
+
+    let parallel = Ray::<f32, 3>::new(Vector::new([-5.0, 2.0, 0.0]), Vector::new([1.0, 0.0, 0.0]));
+    assert_eq!(parallel.intersects_aabb(&aabb), None);
+}
+
+#[test]
+fn ray_hits_triangle_from_either_side() {
+    let triangle = Triangle::<f32>::new(
+        Vector::new([0.0, 0.0, 0.0]),
+        Vector::new([1.0, 0.0, 0.0]),
+        Vector::new([0.0, 1.0, 0.0]),
+    );
+
+    let front = Ray::<f32, 3>::new(
+        Vector::new([0.25, 0.25, 2.0]),
+        Vector::new([0.0, 0.0, -1.0]),
+    );
+    let back = Ray::<f32, 3>::new(
+        Vector::new([0.25, 0.25, -3.0]),
+        Vector::new([0.0, 0.0, 1.0]),
+    );
+    let miss = Ray::<f32, 3>::new(
+        Vector::new([0.75, 0.75, 2.0]),
+        Vector::new([0.0, 0.0, -1.0]),
+    );
+    let parallel = Ray::<f32, 3>::new(Vector::new([0.0, 0.0, 1.0]), Vector::new([1.0, 0.0, 0.0]));
+
+    assert!((front.intersects_triangle(&triangle).unwrap() - 2.0).abs() < EPSILON);
+    assert!((back.intersects_triangle(&triangle).unwrap() - 3.0).abs() < EPSILON);
+    assert_eq!(miss.intersects_triangle(&triangle), None);
+    assert_eq!(parallel.intersects_triangle(&triangle), None);
+}
+
+#[test]
+fn ray_hits_sphere_and_plane() {
+    let sphere = Sphere::<f32, 3>::new(Vector::new([0.0, 0.0, -5.0]), 1.0);
+    let plane = Plane::<f32, 3>::from_point_normal(
+        Vector::new([0.0, -1.0, 0.0]),
+        Vector::new([0.0, 1.0, 0.0]),
+    );
+
+    let ray = Ray::<f32, 3>::new(Vector::new([0.0, 0.0, 0.0]), Vector::new([0.0, 0.0, -1.0]));
+    assert_eq!(ray.intersects_sphere(&sphere), Some(4.0));
+    assert_eq!(ray.intersects_plane(&plane), None);
+
+    let down = Ray::<f32, 3>::new(Vector::new([0.0, 3.0, 0.0]), Vector::new([0.0, -2.0, 0.0]));
+    assert_eq!(down.intersects_plane(&plane), Some(2.0));
+    assert_eq!(down.intersects_sphere(&sphere), None);
+}
+
+#[test]
+fn triangle_closest_point_regions() {
+    let triangle = Triangle::<f32>::new(
+        Vector::new([0.0, 0.0, 0.0]),
+        Vector::new([2.0, 0.0, 0.0]),
+        Vector::new([0.0, 2.0, 0.0]),
+    );
+
+    //vertex, edge and face regions
+    for (point, closest) in [
+        ([-1.0, -1.0, 1.0], [0.0, 0.0, 0.0]),
+        ([3.0, -1.0, 0.0], [2.0, 0.0, 0.0]),
+        ([1.0, -1.0, 0.0], [1.0, 0.0, 0.0]),
+        ([2.0, 2.0, 0.0], [1.0, 1.0, 0.0]),
+        ([0.5, 0.5, 3.0], [0.5, 0.5, 0.0]),
+    ] {
+        assert!(approx(
+            triangle.closest_point(Vector::new(point)),
+            Vector::new(closest)
+        ));
+    }
+
+    assert!(approx(triangle.normal(), Vector::new([0.0, 0.0, 1.0])));
+    assert!((triangle.area() - 2.0).abs() < EPSILON);
+}
+
+#[test]
+fn frustum_culls_spheres() {
+    let view = Matrix::<f32, 4, 4>::look_at(
+        Vector::new([0.0, 0.0, 5.0]),
+        Vector::new([0.0, 0.0, 0.0]),
+        Vector::new([0.0, 1.0, 0.0]),
+    );
+    let projection = Matrix::<f32, 4, 4>::perspective(Radians(FRAC_PI_2), 1.0, 1.0, 10.0);
+    let frustum = Frustum::<f32>::from_matrix(projection * view);
+
+    let visible = |center: [f32; 3], radius| {
+        frustum.intersects_sphere(&Sphere::<f32, 3>::new(Vector::new(center), radius))
+    };
+
+    assert!(frustum.contains_point(Vector::new([0.0, 0.0, 0.0])));
+    assert!(visible([0.0, 0.0, 0.0], 0.5));
+    assert!(!visible([0.0, 0.0, 10.0], 0.5));
+    assert!(!visible([0.0, 0.0, -10.0], 0.5));
+    assert!(visible([0.0, 0.0, -5.5], 1.0));
+    assert!(!visible([8.0, 0.0, 0.0], 1.0));
+    assert!(visible([5.5, 0.0, 0.0], 1.0));
+
+    let aabb = Aabb::new(Vector::new([4.0, -1.0, -1.0]), Vector::new([6.0, 1.0, 1.0]));
+    assert!(frustum.intersects_aabb(&aabb));
+    assert!(!frustum.intersects_aabb(&Aabb::new(
+        Vector::new([0.0, 20.0, 0.0]),
+        Vector::new([1.0, 21.0, 1.0])
+    )));
+}
+
+#[test]
+fn obb_queries_in_local_space() {
+    let obb = Obb::new(
+        Vector::new([1.0, 0.0, 0.0]),
+        Vector::new([2.0, 0.5, 0.5]),
+        Quaternion::<f32>::from_axis_angle(Vector::new([0.0, 0.0, 1.0]), Radians(FRAC_PI_2)),
+    );
+
+    assert!(obb.contains_point(Vector::new([1.0, 1.5, 0.0])));
+    assert!(!obb.contains_point(Vector::new([2.5, 0.0, 0.0])));
+    assert!(approx(
+        obb.closest_point(Vector::new([1.0, 5.0, 0.0])),
+        Vector::new([1.0, 2.0, 0.0])
+    ));
+
+    let aabb = obb.aabb();
+    assert!(approx(aabb.min, Vector::new([0.5, -2.0, -0.5])));
+    assert!(approx(aabb.max, Vector::new([1.5, 2.0, 0.5])));
+
+    let ray = Ray::<f32, 3>::new(Vector::new([1.0, -5.0, 0.0]), Vector::new([0.0, 1.0, 0.0]));
+    assert!((ray.intersects_obb(&obb).unwrap() - 3.0).abs() < EPSILON);
+}
@@ -0,0 +1,327 @@
+use math::prelude::*;
+use proptest::prelude::*;
+use std::f32::consts::FRAC_PI_2;
+
+const EPSILON: f32 = 1e-5;
+
+fn approx(a: Matrix<f32, 4, 4>, b: Matrix<f32, 4, 4>) -> bool {
+    (0..4).all(|row| a[row].distance(&b[row]) < EPSILON)
+}
+
+fn matrix(rows: [[f32; 4]; 4]) -> Matrix<f32, 4, 4> {
+    Matrix::new(rows.map(Vector::new))
+}
+
+#[test]
+fn perspective_matches_reference() {
+    let projection = Matrix::<f32, 4, 4>::perspective(Radians(FRAC_PI_2), 2.0, 1.0, 10.0);
+
+    let reference = matrix([
+        [0.5, 0.0, 0.0, 0.0],
+        [0.0, 1.0, 0.0, 0.0],
+        [0.0, 0.0, -10.0 / 9.0, -10.0 / 9.0],
+        [0.0, 0.0, -1.0, 0.0],
+    ]);
+
+    assert!(approx(projection, reference));
+}
+
+#[test]
+fn perspective_maps_depth_to_unit_range() {
+    let projection = Matrix::<f32, 4, 4>::perspective(Radians(1.2), 1.5, 0.1, 100.0);
+
+    let near = projection * Vector::new([0.0, 0.0, -0.1, 1.0]);
+    let far = projection * Vector::new([0.0, 0.0, -100.0, 1.0]);
+
+    assert!((near[2] / near[3]).abs() < EPSILON);
+    assert!((far[2] / far[3] - 1.0).abs() < EPSILON);
+}
+
+#[test]
+fn orthographic_matches_reference() {
+    let projection = Matrix::<f32, 4, 4>::orthographic(-2.0, 2.0, -1.0, 1.0, 0.0, 10.0);
+
+    let reference = matrix([
+        [0.5, 0.0, 0.0, 0.0],
+        [0.0, 1.0, 0.0, 0.0],
+        [0.0, 0.0, -0.1, 0.0],
+        [0.0, 0.0, 0.0, 1.0],
+    ]);
+
+    assert!(approx(projection, reference));
+}
+
+#[test]
+fn look_at_matches_reference() {
+    let origin = Vector::new([0.0, 0.0, 0.0]);
+    let up = Vector::new([0.0, 1.0, 0.0]);
+
+    let view = Matrix::<f32, 4, 4>::look_at(Vector::new([0.0, 0.0, 5.0]), origin, up);
+
+    assert!(approx(
+        view,
+        Matrix::translation(Vector::new([0.0, 0.0, -5.0]))
+    ));
+
+    let view = Matrix::<f32, 4, 4>::look_at(Vector::new([3.0, 0.0, 0.0]), origin, up);
+
+    let reference = matrix([
+        [0.0, 0.0, -1.0, 0.0],
+        [0.0, 1.0, 0.0, 0.0],
+        [1.0, 0.0, 0.0, -3.0],
+        [0.0, 0.0, 0.0, 1.0],
+    ]);
+
+    assert!(approx(view, reference));
+}
+
+#[test]
+fn transforms_apply_right_to_left() {
+    let translation = Matrix::<f32, 4, 4>::translation(Vector::new([1.0, 2.0, 3.0]));
+    let rotation = Matrix::<f32, 4, 4>::rotation_z(Degrees(90.0));
+    let scale = Matrix::<f32, 4, 4>::scale(Vector::new([2.0, 2.0, 2.0]));
+
+    let point = Vector::new([1.0, 0.0, 0.0, 1.0]);
+
+    let transformed = translation * rotation * scale * point;
+
+    assert!(transformed.distance(&Vector::new([1.0, 4.0, 3.0, 1.0])) < EPSILON);
+}
+
+#[test]
+fn axis_rotations_match_quaternions() {
+    let cases = [
+        (
+            Matrix::<f32, 4, 4>::rotation_x(Radians(0.7)),
+            [1.0, 0.0, 0.0],
+        ),
+        (
+            Matrix::<f32, 4, 4>::rotation_y(Radians(0.7)),
+            [0.0, 1.0, 0.0],
+        ),
+        (
+            Matrix::<f32, 4, 4>::rotation_z(Radians(0.7)),
+            [0.0, 0.0, 1.0],
+        ),
+    ];
+
+    for (rotation, axis) in cases {
+        let quaternion = Quaternion::<f32>::from_axis_angle(Vector::new(axis), Radians(0.7));
+
+        assert!(approx(rotation, Matrix::rotation(quaternion)));
+    }
+}
+
+fn approx64<const N: usize>(a: Matrix<f64, N, N>, b: Matrix<f64, N, N>, epsilon: f64) -> bool {
+    (0..N).all(|row| a[row].distance(&b[row]) < epsilon)
+}
+
+fn matrix64<const N: usize>() -> impl Strategy<Value = Matrix<f64, N, N>> {
+    prop::array::uniform::<_, N>(prop::array::uniform::<_, N>(-10.0..10.0f64))
+        .prop_map(|rows| Matrix::new(rows.map(Vector::new)))
+}
+
+#[test]
+fn permutation_matrix_inverts() {
+    let permutation = Matrix::<f64, 3, 3>::new([
+        Vector::new([0.0, 1.0, 0.0]),
+        Vector::new([0.0, 0.0, 1.0]),
+        Vector::new([1.0, 0.0, 0.0]),
+    ]);
+
+    assert_eq!(permutation.try_inverse(), Some(permutation.transpose()));
+    assert_eq!(permutation.determinant(), 1.0);
+}
+
+#[test]
+fn singular_matrix_has_no_inverse() {
+    let singular = Matrix::<f64, 3, 3>::new([
+        Vector::new([1.0, 2.0, 3.0]),
+        Vector::new([2.0, 4.0, 6.0]),
+        Vector::new([0.0, 1.0, 0.0]),
+    ]);
+
+    assert_eq!(singular.try_inverse(), None);
+    assert_eq!(singular.determinant(), 0.0);
+    assert_eq!(singular.trace(), 5.0);
+
+    //rank two, but rounding leaves the last pivot near 1e-16 instead of 0
+    let rounded = Matrix::<f64, 3, 3>::new([
+        Vector::new([1.0, 2.0, 3.0]),
+        Vector::new([4.0, 5.0, 6.0]),
+        Vector::new([7.0, 8.0, 9.0]),
+    ]);
+
+    assert_eq!(rounded.try_inverse(), None);
+    assert_eq!(rounded.solve(Vector::new([1.0, 1.0, 1.0])), None);
+    assert_eq!(rounded.determinant(), 0.0);
+
+    //the same through the cofactor inverse of an affine transform's linear part,
+    //where the rows scaled by 0.1 round to a determinant near 1e-17
+    for scale in [1.0, 0.1] {
+        let mut affine = Matrix::<f64, 4, 4>::identity();
+        for row in 0..3 {
+            for col in 0..3 {
+                affine[row][col] = rounded[row][col] * scale;
+            }
+        }
+
+        assert_eq!(affine.try_affine_inverse(), None, "{scale}");
+        assert_eq!(affine.try_inverse(), None, "{scale}");
+    }
+}
+
+#[test]
+fn lu_reconstructs_matrix() {
+    let matrix = Matrix::<f64, 3, 3>::new([
+        Vector::new([0.0, 2.0, 1.0]),
+        Vector::new([4.0, 1.0, -2.0]),
+        Vector::new([2.0, 3.0, 5.0]),
+    ]);
+
+    let lu = matrix.lu().unwrap();
+    let product = lu.lower() * lu.upper();
+
+    for (row, source) in lu.permutation().into_iter().enumerate() {
+        assert!(product[row].distance(&matrix[source]) < 1e-12);
+    }
+
+    assert!((lu.determinant() - -38.0).abs() < 1e-12);
+}
+
+#[test]
+fn least_squares_fits_line() {
+    //noisy points near y = 2x + 1, with the exact fit worked by hand
+    let xs = [0.0, 1.0, 2.0, 3.0, 4.0];
+    let ys = [1.1, 2.9, 5.0, 7.1, 8.9];
+    let design = Matrix::<f64, 2, 5>::new(xs.map(|x| Vector::new([x, 1.0])));
+
+    let fit = design.least_squares(Vector::new(ys)).unwrap();
+
+    assert!((fit[0] - 1.98).abs() < 1e-12);
+    assert!((fit[1] - 1.04).abs() < 1e-12);
+}
+
+#[test]
+fn qr_reconstructs_matrix() {
+    let matrix = Matrix::<f64, 3, 4>::new([
+        Vector::new([1.0, -1.0, 4.0]),
+        Vector::new([1.0, 4.0, -2.0]),
+        Vector::new([1.0, 4.0, 2.0]),
+        Vector::new([1.0, -1.0, 0.0]),
+    ]);
+
+    let qr = matrix.qr();
+    let (q, r) = (qr.q(), qr.r());
+
+    assert!(approx64(q.transpose() * q, Matrix::identity(), 1e-12));
+    let product = q * r;
+    for row in 0..4 {
+        assert!(product[row].distance(&matrix[row]) < 1e-12);
+    }
+    for row in 1..3 {
+        for col in 0..row {
+            assert_eq!(r[row][col], 0.0);
+        }
+    }
+}
+
+#[test]
+fn symmetric_eigen_diagonalizes() {
+    let matrix = Matrix::<f64, 3, 3>::new([
+        Vector::new([4.0, 1.0, -2.0]),
+        Vector::new([1.0, 2.0, 0.0]),
+        Vector::new([-2.0, 0.0, 3.0]),
+    ]);
+
+    let eigen = matrix.symmetric_eigen();
+
+    assert!(eigen.values[0] >= eigen.values[1] && eigen.values[1] >= eigen.values[2]);
+    assert!((eigen.values.into_iter().sum::<f64>() - matrix.trace()).abs() < 1e-12);
+    assert!(approx64(
+        eigen.vectors.transpose() * eigen.vectors,
+        Matrix::identity(),
+        1e-12
+    ));
+    for i in 0..3 {
+        let vector = Vector::new([0, 1, 2].map(|row| eigen.vectors[row][i]));
+        assert!((matrix * vector).distance(&(vector * eigen.values[i])) < 1e-12);
+    }
+    assert!((eigen.vectors.determinant() - 1.0).abs() < 1e-12);
+}
+
+#[test]
+fn symmetric_eigen_vectors_are_a_rotation_in_any_order() {
+    let diagonals = [
+        [1.0, 2.0, 3.0],
+        [1.0, 3.0, 2.0],
+        [2.0, 1.0, 3.0],
+        [2.0, 3.0, 1.0],
+        [3.0, 1.0, 2.0],
+        [3.0, 2.0, 1.0],
+    ];
+
+    for diagonal in diagonals {
+        let mut matrix = Matrix::<f64, 3, 3>::identity();
+        for i in 0..3 {
+            matrix[i][i] = diagonal[i];
+        }
+
+        let eigen = matrix.symmetric_eigen();
+
+        assert_eq!(eigen.values, Vector::new([3.0, 2.0, 1.0]));
+        assert_eq!(eigen.vectors.determinant(), 1.0, "{diagonal:?}");
+    }
+}
+
+proptest! {
+    #[test]
+    fn solve_satisfies_system(
+        matrix in matrix64::<4>(),
+        b in prop::array::uniform4(-10.0..10.0f64),
+    ) {
+        prop_assume!(matrix.determinant().abs() > 1e-3);
+
+        let b = Vector::new(b);
+        let x = matrix.solve(b).unwrap();
+
+        prop_assert!((matrix * x).distance(&b) < 1e-6);
+    }
+    #[test]
+    fn inverse_is_identity(matrix in matrix64::<4>()) {
+        prop_assume!(matrix.determinant().abs() > 1e-3);
+
+        let inverse = matrix.try_inverse().unwrap();
+
+        prop_assert!(approx64(matrix * inverse, Matrix::identity(), 1e-6));
+        prop_assert!(approx64(inverse * matrix, Matrix::identity(), 1e-6));
+    }
+
+    #[test]
+    fn determinant_is_multiplicative(a in matrix64::<3>(), b in matrix64::<3>()) {
+        let product = (a * b).determinant();
+        let expected = a.determinant() * b.determinant();
+
+        prop_assert!((product - expected).abs() <= 1e-9 * expected.abs().max(1.0));
+    }
+
+    #[test]
+    fn affine_inverse_matches_inverse(
+        x in -3.0..3.0f64,
+        y in -3.0..3.0f64,
+        z in -3.0..3.0f64,
+        scale in 0.1..4.0f64,
+        translation in prop::array::uniform3(-10.0..10.0f64),
+    ) {
+        let transform = Matrix::<f64, 4, 4>::translation(Vector::new(translation))
+            * Matrix::<f64, 4, 4>::rotation_z(Radians(z))
+            * Matrix::<f64, 4, 4>::rotation_y(Radians(y))
+            * Matrix::<f64, 4, 4>::rotation_x(Radians(x))
+            * Matrix::scale(Vector::new([scale, scale * 2.0, 1.0]));
+
+        let affine = transform.try_affine_inverse().unwrap();
+
+        prop_assert!(approx64(affine, transform.inverse(), 1e-9));
+        prop_assert!(approx64(transform * affine, Matrix::identity(), 1e-9));
+    }
+}
@@ -0,0 +1,99 @@
+use math::prelude::*;
+
+const EPSILON: f32 = 1e-6;
+
+fn points<const N: usize>(seed: u64, count: usize) -> Vec<Vector<f32, N>> {
+    let mut random = Pcg32::new(seed);
+    (0..count)
+        .map(|_| Vector::new([(); N].map(|_| random.range(-16.0..16.0))))
+        .collect()
+}
+
+//bounded, and continuous in that a small step never jumps far
+fn check_gradient_noise<const N: usize>(noise: &impl Noise<N>) {
+    for point in points::<N>(N as u64, 2000) {
+        let value = noise.sample(point);
+        assert!((-1.0..=1.0).contains(&value));
+
+        let mut nearby = point;
+        nearby[N - 1] += 1e-4;
+        assert!((noise.sample(nearby) - value).abs() < 1e-2);
+    }
+
+    //gradient noise is zero on the integer lattice
+    let origin = noise.sample(Vector::new([0.0; N]));
+    assert!(origin.abs() < EPSILON);
+}
+
+#[test]
+fn golden_values() {
+    let p2 = Vector::new([0.3, 1.7]);
+    let p3 = Vector::new([0.3, 1.7, -2.4]);
+    let p4 = Vector::new([0.3, 1.7, -2.4, 5.1]);
+
+    for (value, expected) in [
+        (Perlin::new(1).sample(p2), -0.037232876),
+        (Perlin::new(1).sample(p3), -0.07984227),
+        (Perlin::new(1).sample(p4), 0.089976974),
+        (OpenSimplex2::new(1).sample(p2), -0.071841106),
+        (OpenSimplex2::new(1).sample(p3), 0.2748151),
+        (OpenSimplex2::new(1).sample(p4), 0.2037641),
+        (Worley::new(1).sample(p2), 0.43889868),
+        (Worley::new(1).sample(p3), 0.50775737),
+        (Worley::new(1).sample(p4), 0.67044705),
+        (Fbm::new(Perlin::new(1), 4).sample(p3), -0.0064891935),
+        (Ridged::new(OpenSimplex2::new(1), 4).sample(p2), 0.3015703),
+    ] {
+        assert!((value - expected).abs() < EPSILON, "{value} != {expected}");
+    }
+}
+
+#[test]
+fn seeds_change_the_pattern() {
+    let point = Vector::new([3.25, -7.5, 0.125]);
+
+    assert_eq!(Perlin::new(4).sample(point), Perlin::new(4).sample(point));
+    assert_ne!(Perlin::new(4).sample(point), Perlin::new(5).sample(point));
+    assert_ne!(
+        OpenSimplex2::new(4).sample(point),
+        OpenSimplex2::new(5).sample(point)
+    );
+    assert_ne!(Worley::new(4).sample(point), Worley::new(5).sample(point));
+}
+
+#[test]
+fn gradient_noise_is_bounded_and_smooth() {
+    check_gradient_noise::<2>(&Perlin::new(2));
+    check_gradient_noise::<3>(&Perlin::new(2));
+    check_gradient_noise::<4>(&Perlin::new(2));
+    check_gradient_noise::<2>(&OpenSimplex2::new(2));
+    check_gradient_noise::<3>(&OpenSimplex2::new(2));
+    check_gradient_noise::<4>(&OpenSimplex2::new(2));
+}
+
+#[test]
+fn worley_orders_distances() {
+    let worley = Worley::new(3);
+
+    for point in points::<3>(7, 500) {
+        let (first, second) = worley.distances(point);
+        assert!(first <= second);
+        //the feature point of the containing cell is at most a diagonal away
+        assert!(first <= 3.0_f32.sqrt());
+    }
+}
+
+#[test]
+fn fractal_combinators() {
+    let perlin = Perlin::new(6);
+    let point = Vector::new([1.5, 2.25]);
+
+    assert_eq!(Fbm::new(perlin, 1).sample(point), perlin.sample(point));
+    assert_eq!(Fbm::new(perlin, 0).sample(point), 0.0);
+
+    for point in points::<2>(8, 500) {
+        let ridged = Ridged::new(perlin, 5).sample(point);
+        assert!((-1.0..=1.0).contains(&ridged));
+        assert!(Fbm::new(perlin, 5).sample(point).abs() <= 1.0);
+    }
+}
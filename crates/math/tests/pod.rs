@@ -0,0 +1,47 @@
+use math::{pod::as_bytes, prelude::*};
+
+math::pod! {
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Vertex {
+        position: Vector<f32, 4>,
+        color: LinearRgba,
+        weights: [u16; 2],
+        id: u32,
+    }
+}
+
+#[test]
+fn vertices_upload_as_bytes() {
+    let vertex = Vertex {
+        position: Vector::new([1.0, 2.0, 3.0, 4.0]),
+        color: LinearRgba::RED,
+        weights: [1, 2],
+        id: 0x01020304,
+    };
+
+    let vertices = [vertex, vertex];
+    let bytes = as_bytes(&vertices);
+    assert_eq!(bytes.len(), 2 * 40);
+    assert_eq!(&bytes[..4], &1.0_f32.to_ne_bytes());
+    assert_eq!(&bytes[16..20], &1.0_f32.to_ne_bytes());
+    assert_eq!(&bytes[32..34], &1_u16.to_ne_bytes());
+    assert_eq!(&bytes[36..40], &0x01020304_u32.to_ne_bytes());
+    assert_eq!(&bytes[..40], &bytes[40..]);
+}
+
+#[test]
+fn math_types_are_their_components() {
+    let matrix = Matrix::<f32, 4, 4>::identity();
+    let floats = matrix.iter().flat_map(|row| **row).collect::<Vec<_>>();
+    assert_eq!(as_bytes(&[matrix]), as_bytes(&floats));
+
+    let rotation = Quaternion::new([
+        Q16_16::from_bits(7),
+        Q16_16::zero(),
+        Q16_16::zero(),
+        Q16_16::one(),
+    ]);
+    assert_eq!(as_bytes(&[rotation]), as_bytes(&[7_i32, 0, 0, 1 << 16]));
+
+    assert_eq!(Vertex::zeroed().position, Vector::default());
+}
@@ -0,0 +1,91 @@
+use math::prelude::*;
+use std::f32::consts::FRAC_PI_2;
+
+const EPSILON: f32 = 1e-5;
+
+fn approx_vector(a: Vector<f32, 3>, b: Vector<f32, 3>) -> bool {
+    a.distance(&b) < EPSILON
+}
+
+fn approx_quaternion(a: Quaternion<f32>, b: Quaternion<f32>) -> bool {
+    //q and -q describe the same rotation
+    (a.dot(b).abs() - 1.0).abs() < EPSILON
+}
+
+#[test]
+fn axis_angle_rotates_vectors() {
+    let rotation =
+        Quaternion::<f32>::from_axis_angle(Vector::new([0.0, 0.0, 1.0]), Radians(FRAC_PI_2));
+
+    let rotated = rotation * Vector::new([1.0, 0.0, 0.0]);
+
+    assert!(approx_vector(rotated, Vector::new([0.0, 1.0, 0.0])));
+}
+
+#[test]
+fn product_applies_right_hand_side_first() {
+    let x = Quaternion::<f32>::from_axis_angle(Vector::new([1.0, 0.0, 0.0]), Radians(FRAC_PI_2));
+    let z = Quaternion::<f32>::from_axis_angle(Vector::new([0.0, 0.0, 1.0]), Radians(FRAC_PI_2));
+
+    let v = Vector::new([0.0, 1.0, 0.0]);
+
+    assert!(approx_vector((z * x) * v, z * (x * v)));
+    assert!(approx_vector((z * x) * v, Vector::new([0.0, 0.0, 1.0])));
+}
+
+#[test]
+fn inverse_undoes_rotation() {
+    let rotation = Quaternion::<f32>::from_euler(Radians(0.3), Radians(-1.2), Radians(2.0)) * 2.0;
+
+    assert!(approx_quaternion(
+        rotation * rotation.inverse(),
+        Quaternion::identity()
+    ));
+    assert!(approx_quaternion(
+        rotation.normalize().conjugate(),
+        rotation.inverse().normalize()
+    ));
+}
+
+#[test]
+fn euler_matches_axis_rotations() {
+    let euler = Quaternion::<f32>::from_euler(Radians(0.4), Radians(0.0), Radians(0.0));
+    let axis = Quaternion::<f32>::from_axis_angle(Vector::new([1.0, 0.0, 0.0]), Radians(0.4));
+
+    assert!(approx_quaternion(euler, axis));
+}
+
+#[test]
+fn matrix_round_trip() {
+    for rotation in [
+        Quaternion::identity(),
+        Quaternion::<f32>::from_euler(Radians(0.3), Radians(-1.2), Radians(2.0)),
+        Quaternion::<f32>::from_euler(Radians(3.1), Radians(0.1), Radians(-0.2)),
+        Quaternion::<f32>::from_axis_angle(Vector::new([0.0, 1.0, 0.0]), Radians(3.0)),
+    ] {
+        let matrix = Matrix::<f32, 3, 3>::from(rotation);
+        let v = Vector::new([0.2, -0.7, 1.5]);
+
+        assert!(approx_vector(matrix * v, rotation * v));
+        assert!(approx_quaternion(Quaternion::from(matrix), rotation));
+
+        let matrix = Matrix::<f32, 4, 4>::from(rotation);
+
+        assert_eq!(matrix[3], Vector::new([0.0, 0.0, 0.0, 1.0]));
+        assert!(approx_quaternion(Quaternion::from(matrix), rotation));
+    }
+}
+
+#[test]
+fn interpolation_hits_endpoints_and_midpoint() {
+    let a = Quaternion::<f32>::identity();
+    let b = Quaternion::<f32>::from_axis_angle(Vector::new([0.0, 1.0, 0.0]), Radians(FRAC_PI_2));
+    let half =
+        Quaternion::<f32>::from_axis_angle(Vector::new([0.0, 1.0, 0.0]), Radians(FRAC_PI_2 / 2.0));
+
+    assert!(approx_quaternion(a.slerp(b, 0.0), a));
+    assert!(approx_quaternion(a.slerp(b, 1.0), b));
+    assert!(approx_quaternion(a.slerp(b, 0.5), half));
+    assert!(approx_quaternion(a.nlerp(b, 0.5), half));
+    assert!(approx_quaternion(a.slerp(a, 0.5), a));
+}
@@ -0,0 +1,125 @@
+use math::prelude::*;
+
+#[test]
+fn matches_reference_pcg32() {
+    //the first outputs of the reference pcg32 demo, seeded with 42 on stream 54
+    let mut random = Pcg32::with_stream(42, 54);
+    let outputs = [0; 6].map(|_| random.next_u32());
+
+    assert_eq!(
+        outputs,
+        [0xa15c02b7, 0x7b47f409, 0xba1d3330, 0x83d2f293, 0xbfa4784b, 0xcbed606e]
+    );
+}
+
+#[test]
+fn same_seed_same_sequence() {
+    let mut a = Pcg32::new(7);
+    let mut b = Pcg32::new(7);
+    let mut c = Pcg32::new(8);
+
+    let first = [0; 8].map(|_| a.next_u64());
+    assert_eq!(first, [0; 8].map(|_| b.next_u64()));
+    assert_ne!(first, [0; 8].map(|_| c.next_u64()));
+}
+
+#[test]
+fn ranges_stay_in_bounds() {
+    let mut random = Pcg32::new(1);
+    let mut seen = [false; 5];
+
+    for _ in 0..1000 {
+        let value = random.range(-2..3_i32);
+        assert!((-2..3).contains(&value));
+        seen[(value + 2) as usize] = true;
+
+        let float = random.range(1.5..2.5_f32);
+        assert!((1.5..2.5).contains(&float));
+
+        let unit = random.next_f64();
+        assert!((0.0..1.0).contains(&unit));
+    }
+
+    assert!(seen.iter().all(|seen| *seen));
+    assert_eq!(random.range(u64::MAX - 1..u64::MAX), u64::MAX - 1);
+}
+
+#[test]
+fn samples_unit_vectors_and_balls() {
+    let mut random = Pcg32::new(3);
+    let mut mean = Vector::<f32, 3>::default();
+
+    for _ in 0..1000 {
+        let direction = random.unit_vector::<3>();
+        assert!((direction.magnitude() - 1.0).abs() < 1e-5);
+        mean += direction / 1000.0;
+
+        assert!(random.in_sphere::<3>().magnitude() <= 1.0);
+        assert!(random.in_disk().magnitude() <= 1.0);
+        assert!((random.unit_vector::<2>().magnitude() - 1.0).abs() < 1e-5);
+
+        let rotation = random.quaternion();
+        assert!((rotation.magnitude() - 1.0).abs() < 1e-5);
+    }
+
+    assert!(mean.magnitude() < 0.1);
+}
+
+#[test]
+fn weighted_choice_and_shuffle() {
+    let mut random = Pcg32::new(5);
+    let mut counts = [0; 4];
+
+    for _ in 0..4000 {
+        counts[random.weighted(&[1.0, 0.0, 3.0, -2.0]).unwrap()] += 1;
+    }
+
+    assert_eq!(counts[1], 0);
+    assert_eq!(counts[3], 0);
+    assert!((2700..3300).contains(&counts[2]));
+    assert_eq!(random.weighted(&[0.0, -1.0]), None);
+    assert_eq!(random.choose::<u8>(&[]), None);
+
+    let mut items = [0, 1, 2, 3, 4, 5, 6, 7];
+    random.shuffle(&mut items);
+    let mut sorted = items;
+    sorted.sort();
+    assert_eq!(sorted, [0, 1, 2, 3, 4, 5, 6, 7]);
+    assert_ne!(items, sorted);
+}
+
+#[test]
+fn sampling_matches_golden_values() {
+    //recorded on x86_64, sampling must give the same values on every target
+    let mut random = Pcg32::new(11);
+
+    assert_eq!(
+        [0; 6].map(|_| random.range(-10..10_i32)),
+        [7, -2, 1, 3, -4, -2]
+    );
+    assert_eq!(
+        [0; 3].map(|_| random.range(1.0..2.0_f32)),
+        [1.1533377, 1.0508915, 1.2772064]
+    );
+    assert_eq!(
+        [0; 3].map(|_| random.range(0..1_000_000_007_u64)),
+        [503288829, 552080611, 194406710]
+    );
+
+    let mut random = Pcg32::new(11);
+
+    assert_eq!(
+        random.unit_vector::<3>(),
+        Vector::new([0.92833453, -0.33275968, -0.16572823])
+    );
+    assert_eq!(
+        random.unit_vector::<3>(),
+        Vector::new([-0.31701908, 0.21010737, 0.9248534])
+    );
+
+    let mut random = Pcg32::new(11);
+    let mut items = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+    random.shuffle(&mut items);
+
+    assert_eq!(items, [5, 6, 9, 0, 2, 1, 7, 4, 3, 8]);
+}
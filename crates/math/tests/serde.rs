@@ -0,0 +1,64 @@
+#![cfg(feature = "serde")]
+
+use math::prelude::*;
+use serde_json::{from_str, to_string};
+
+#[test]
+fn math_types_are_plain_arrays() {
+    let vector = Vector::new([1.0, -2.5, 3.0]);
+    assert_eq!(to_string(&vector).unwrap(), "[1.0,-2.5,3.0]");
+    assert_eq!(
+        from_str::<Vector<f64, 3>>("[1.0,-2.5,3.0]").unwrap(),
+        vector
+    );
+
+    let matrix = Matrix::<i32, 2, 3>::new([
+        Vector::new([1, 2]),
+        Vector::new([3, 4]),
+        Vector::new([5, 6]),
+    ]);
+    assert_eq!(to_string(&matrix).unwrap(), "[[1,2],[3,4],[5,6]]");
+    assert_eq!(
+        from_str::<Matrix<i32, 2, 3>>("[[1,2],[3,4],[5,6]]").unwrap(),
+        matrix
+    );
+
+    assert_eq!(
+        to_string(&Quaternion::<f32>::identity()).unwrap(),
+        "[0.0,0.0,0.0,1.0]"
+    );
+    assert_eq!(to_string(&LinearRgba::RED).unwrap(), "[1.0,0.0,0.0,1.0]");
+    assert_eq!(to_string(&Degrees(90.0)).unwrap(), "90.0");
+    assert_eq!(from_str::<EulerRot>("\"YXZ\"").unwrap(), EulerRot::YXZ);
+    assert_eq!(to_string(&Q16_16::one()).unwrap(), "65536");
+}
+
+#[test]
+fn round_trips() {
+    let transform = Transform {
+        translation: Vector::new([1.0_f32, 2.0, 3.0]),
+        rotation: Quaternion::from_axis_angle(Vector::new([0.0, 1.0, 0.0]), Radians(0.5)),
+        scale: Vector::new([2.0, 2.0, 2.0]),
+    };
+    let json = to_string(&transform).unwrap();
+    assert_eq!(from_str::<Transform<f32>>(&json).unwrap(), transform);
+
+    let color = Oklab::from(Srgba::from_u32(0x336699ff));
+    assert_eq!(
+        from_str::<Oklab>(&to_string(&color).unwrap()).unwrap(),
+        color
+    );
+
+    let big = Vector::new([7_u8; 40]);
+    assert_eq!(
+        from_str::<Vector<u8, 40>>(&to_string(&big).unwrap()).unwrap(),
+        big
+    );
+}
+
+#[test]
+fn rejects_wrong_lengths() {
+    assert!(from_str::<Vector<f32, 3>>("[1.0,2.0]").is_err());
+    assert!(from_str::<Vector<f32, 3>>("[1.0,2.0,3.0,4.0]").is_err());
+    assert!(from_str::<Matrix<f32, 2, 2>>("[[1.0,2.0],[3.0]]").is_err());
+}
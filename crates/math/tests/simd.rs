@@ -0,0 +1,91 @@
+#![cfg(feature = "simd")]
+
+use math::prelude::*;
+use proptest::prelude::*;
+
+//the scalar loops the simd operators replace, in the same order
+fn scalar_matrix_mul(a: [[f32; 4]; 4], b: [[f32; 4]; 4]) -> [[f32; 4]; 4] {
+    let mut data = [[0.0; 4]; 4];
+    for row in 0..4 {
+        for col in 0..4 {
+            let mut cell = 0.0;
+            for i in 0..4 {
+                cell += a[row][i] * b[i][col];
+            }
+            data[row][col] = cell;
+        }
+    }
+    data
+}
+
+fn scalar_matrix_vector_mul(a: [[f32; 4]; 4], v: [f32; 4]) -> [f32; 4] {
+    let mut data = [0.0; 4];
+    for row in 0..4 {
+        let mut cell = 0.0;
+        for i in 0..4 {
+            cell += a[row][i] * v[i];
+        }
+        data[row] = cell;
+    }
+    data
+}
+
+fn matrix(data: [[f32; 4]; 4]) -> Matrix<f32, 4, 4> {
+    Matrix::new(data.map(Vector::new))
+}
+
+fn bits<const N: usize>(data: [f32; N]) -> [u32; N] {
+    data.map(f32::to_bits)
+}
+
+fn float() -> impl Strategy<Value = f32> {
+    prop_oneof![
+        -1e3_f32..1e3,
+        any::<f32>(),
+        Just(0.0),
+        Just(-0.0),
+        Just(f32::INFINITY),
+    ]
+}
+
+fn vector() -> impl Strategy<Value = [f32; 4]> {
+    prop::array::uniform4(float())
+}
+
+fn matrix_data() -> impl Strategy<Value = [[f32; 4]; 4]> {
+    prop::array::uniform4(vector())
+}
+
+proptest! {
+    #[test]
+    fn vector_ops_match_scalar(a in vector(), b in vector(), s in float()) {
+        let (va, vb) = (Vector::new(a), Vector::new(b));
+
+        let mut expected = [[0.0; 4]; 6];
+        for i in 0..4 {
+            expected[0][i] = a[i] + b[i];
+            expected[1][i] = a[i] - b[i];
+            expected[2][i] = a[i] * s;
+            expected[3][i] = a[i] / s;
+            expected[4][i] = a[i] * b[i];
+            expected[5][i] = a[i] / b[i];
+        }
+
+        let actual = [va + vb, va - vb, va * s, va / s, va * vb, va / vb];
+        for (actual, expected) in actual.iter().zip(expected) {
+            prop_assert_eq!(bits(**actual), bits(expected));
+        }
+    }
+
+    #[test]
+    fn matrix_ops_match_scalar(a in matrix_data(), b in matrix_data(), v in vector()) {
+        let product = matrix(a) * matrix(b);
+        let expected = scalar_matrix_mul(a, b);
+        for row in 0..4 {
+            prop_assert_eq!(bits(*product[row]), bits(expected[row]));
+        }
+
+        let transformed = matrix(a) * Vector::new(v);
+        prop_assert_eq!(bits(*transformed), bits(scalar_matrix_vector_mul(a, v)));
+    }
+}
@@ -0,0 +1,112 @@
+use math::prelude::*;
+
+const EPSILON: f32 = 1e-5;
+
+fn approx(a: Vector<f32, 3>, b: Vector<f32, 3>) -> bool {
+    a.distance(&b) < EPSILON
+}
+
+fn transform() -> Transform<f32> {
+    Transform {
+        translation: Vector::new([1.0, -2.0, 3.0]),
+        rotation: Quaternion::<f32>::from_euler(Radians(0.3), Radians(-0.8), Radians(1.9)),
+        scale: Vector::new([2.0, 0.5, 3.0]),
+    }
+}
+
+#[test]
+fn matrix_matches_transform_point() {
+    let transform = transform();
+    let matrix = Matrix::<f32, 4, 4>::from(transform);
+
+    let point = Vector::new([0.4, 1.0, -2.0]);
+    let transformed = matrix * Vector::new([point[0], point[1], point[2], 1.0]);
+
+    assert!(approx(
+        transform.transform_point(point),
+        Vector::new([transformed[0], transformed[1], transformed[2]])
+    ));
+}
+
+#[test]
+fn matrix_decomposes_into_transform() {
+    let transform = transform();
+
+    let decomposed = Transform::<f32>::from(transform.to_matrix());
+
+    assert!(approx(decomposed.translation, transform.translation));
+    assert!(approx(decomposed.scale, transform.scale));
+    assert!((decomposed.rotation.dot(transform.rotation).abs() - 1.0).abs() < EPSILON);
+}
+
+#[test]
+fn composition_applies_right_hand_side_first() {
+    let parent = Transform {
+        scale: Vector::new([2.0, 2.0, 2.0]),
+        ..transform()
+    };
+    let child = Transform::from_translation(Vector::new([0.0, 1.0, 0.0]));
+
+    let point = Vector::new([1.0, 2.0, 3.0]);
+
+    assert!(approx(
+        (parent * child).transform_point(point),
+        parent.transform_point(child.transform_point(point))
+    ));
+}
+
+#[test]
+fn composition_matches_matrix_product_for_uniform_parent_scale() {
+    let parent = Transform {
+        scale: Vector::new([0.5, 0.5, 0.5]),
+        ..transform()
+    };
+    let child = Transform {
+        translation: Vector::new([-1.0, 4.0, 0.5]),
+        rotation: Quaternion::<f32>::from_euler(Radians(-1.1), Radians(0.4), Radians(0.2)),
+        scale: Vector::new([3.0, 1.0, 0.25]),
+    };
+
+    let composed = (parent * child).to_matrix();
+    let product = parent.to_matrix() * child.to_matrix();
+
+    for row in 0..4 {
+        assert!(composed[row].distance(&product[row]) < EPSILON);
+    }
+}
+
+#[test]
+fn inverse_undoes_uniform_transform() {
+    let transform = Transform {
+        scale: Vector::new([1.5, 1.5, 1.5]),
+        ..transform()
+    };
+
+    let point = Vector::new([-3.0, 0.5, 2.0]);
+
+    assert!(approx(transform.inverse() * (transform * point), point));
+    assert!(approx((transform * transform.inverse()) * point, point));
+}
+
+#[test]
+fn vectors_ignore_translation() {
+    let transform = Transform::<f32>::from_translation(Vector::new([5.0, 5.0, 5.0]));
+
+    let vector = Vector::new([1.0, 0.0, 0.0]);
+
+    assert!(approx(transform.transform_vector(vector), vector));
+}
+
+#[test]
+fn lerp_hits_endpoints() {
+    let a = Transform::<f32>::identity();
+    let b = transform();
+
+    let start = a.lerp(b, 0.0);
+    let end = a.lerp(b, 1.0);
+
+    assert!(approx(start.translation, a.translation));
+    assert!(approx(end.translation, b.translation));
+    assert!(approx(end.scale, b.scale));
+    assert!(approx(a.lerp(b, 0.5).translation, b.translation * 0.5));
+}
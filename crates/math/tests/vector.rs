@@ -0,0 +1,74 @@
+use math::prelude::*;
+use std::f32::consts::PI;
+
+const EPSILON: f32 = 1e-5;
+
+#[test]
+fn component_wise_operators() {
+    let a = Vector::new([1.0, -2.0, 3.0]);
+    let b = Vector::new([2.0, 4.0, -1.0]);
+
+    assert_eq!(a * b, Vector::new([2.0, -8.0, -3.0]));
+    assert_eq!(a / b, Vector::new([0.5, -0.5, -3.0]));
+    assert_eq!(-a, Vector::new([-1.0, 2.0, -3.0]));
+    assert_eq!(a.min(b), Vector::new([1.0, -2.0, -1.0]));
+    assert_eq!(a.max(b), Vector::new([2.0, 4.0, 3.0]));
+    assert_eq!(a.abs(), Vector::new([1.0, 2.0, 3.0]));
+    assert_eq!(
+        a.clamp(Vector::new([0.0; 3]), Vector::new([2.0; 3])),
+        Vector::new([1.0, 0.0, 2.0])
+    );
+    assert_eq!(
+        Vector::<u32, 2>::new([3, 7]).min(Vector::new([5, 1])),
+        Vector::new([3, 1])
+    );
+}
+
+#[test]
+fn rounding_and_interpolation() {
+    let a = Vector::new([1.5_f32, -1.5]);
+
+    assert_eq!(a.floor(), Vector::new([1.0, -2.0]));
+    assert_eq!(a.ceil(), Vector::new([2.0, -1.0]));
+    assert_eq!(
+        a.lerp(Vector::new([3.5, 0.5]), 0.5),
+        Vector::new([2.5, -0.5])
+    );
+    assert!(a.is_finite());
+    assert!(!Vector::new([0.0, f32::NAN]).is_finite());
+}
+
+#[test]
+fn reflect_project_and_angle() {
+    let v = Vector::new([1.0_f32, -1.0, 0.0]);
+    let up = Vector::new([0.0, 1.0, 0.0]);
+
+    assert_eq!(v.reflect(up), Vector::new([1.0, 1.0, 0.0]));
+    assert_eq!(v.project(up * 2.0), Vector::new([0.0, -1.0, 0.0]));
+    assert!((v.angle_between(up).0 - 3.0 * PI / 4.0).abs() < EPSILON);
+    let right = Vector::new([1.0_f32, 0.0]).angle_between(Vector::new([0.0, 3.0]));
+    assert!((right.to_degrees().0 - 90.0).abs() < 1e-4);
+    assert!(up.angle_between(up).0.abs() < EPSILON);
+}
+
+#[test]
+fn accessors_and_swizzles() {
+    let v = Vector::new([1, 2, 3, 4]);
+
+    assert_eq!((v.x(), v.y(), v.z(), v.w()), (1, 2, 3, 4));
+    assert_eq!(v.xy(), Vector::new([1, 2]));
+    assert_eq!(v.xyz(), Vector::new([1, 2, 3]));
+    assert_eq!(v.zyx(), Vector::new([3, 2, 1]));
+    assert_eq!(v.wzyx(), Vector::new([4, 3, 2, 1]));
+    assert_eq!(v.xyz().yx(), Vector::new([2, 1]));
+}
+
+#[test]
+fn extend_and_truncate() {
+    let v = Vector::new([1, 2, 3]);
+
+    assert_eq!(v.extend::<4>(9), Vector::new([1, 2, 3, 9]));
+    assert_eq!(v.extend::<3>(9), v);
+    assert_eq!(v.truncate::<2>(), Vector::new([1, 2]));
+    assert_eq!(v.truncate::<0>(), Vector::new([]));
+}
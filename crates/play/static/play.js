@@ -1,10 +1,84 @@
 import init from "/engine/engine.js";
 import * as engine from "/engine/engine.js";
 
+function inspector(runtime) {
+	var panel = document.getElementById("inspector");
+	var list = document.createElement("ul");
+	var details = document.createElement("div");
+	var selected = null;
+
+	panel.appendChild(list);
+	panel.appendChild(details);
+
+	function field(component, name, value) {
+		var label = document.createElement("label");
+		label.textContent = name;
+
+		var input = document.createElement("input");
+		input.value = JSON.stringify(value);
+		input.onchange = function() {
+			var parsed;
+			try {
+				parsed = JSON.parse(input.value);
+			} catch (error) {
+				parsed = input.value;
+			}
+			runtime.set_field(selected, component, name, parsed);
+			inspect();
+		};
+
+		label.appendChild(input);
+		return label;
+	}
+
+	function inspect() {
+		details.replaceChildren();
+
+		var inspection = selected === null ? undefined : runtime.inspect(selected);
+		if (inspection === undefined) {
+			return;
+		}
+
+		var components = JSON.parse(inspection).components;
+
+		for (var component in components) {
+			var fieldset = document.createElement("fieldset");
+			var legend = document.createElement("legend");
+			legend.textContent = component;
+			fieldset.appendChild(legend);
+
+			for (var name in components[component] || {}) {
+				fieldset.appendChild(field(component, name, components[component][name]));
+			}
+
+			details.appendChild(fieldset);
+		}
+	}
+
+	function refresh() {
+		list.replaceChildren();
+
+		for (var data of JSON.parse(runtime.entities())) {
+			var item = document.createElement("li");
+			item.textContent = "entity " + data.entity + " (archetype " + data.archetype + ")";
+			item.onclick = function(entity) {
+				selected = entity;
+				inspect();
+			}.bind(null, data.entity);
+			list.appendChild(item);
+		}
+	}
+
+	refresh();
+	setInterval(refresh, 1000);
+}
+
 async function run() {
 	var runtime = await engine.start();
 	console.log(runtime);
 
+	inspector(runtime);
+
 	async function frame() {
 		await runtime.next();
 		requestAnimationFrame(frame);